@@ -0,0 +1,10 @@
+use anchor_lang::prelude::error_code;
+
+#[error_code]
+pub enum FeeCollectorError {
+    Unauthorized,
+    InvalidAmount,
+    InsufficientFunds,
+    ActionNotPriced,
+    NoPendingAdmin,
+}
@@ -0,0 +1,61 @@
+use crate::*;
+
+#[event]
+pub struct FeeCollected {
+    pub fee_config: Pubkey,
+    pub from: Pubkey,
+    pub action: FeeAction,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TokenFeeCollected {
+    pub fee_config: Pubkey,
+    pub from: Pubkey,
+    pub mint: Pubkey,
+    pub action: FeeAction,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeWithdrawn {
+    pub fee_config: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>, // None for SOL
+    pub amount: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub fee_config: Pubkey,
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferStarted {
+    pub fee_config: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub fee_config: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub fee_config: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub fee_config: Pubkey,
+    pub mint: Option<Pubkey>, // None for the lamport schedule
+    pub action: FeeAction,
+    pub amount: Option<u64>,
+}
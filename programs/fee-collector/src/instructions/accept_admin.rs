@@ -0,0 +1,28 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump,
+        constraint = fee_config.pending_admin == Some(pending_admin.key())
+            @FeeCollectorError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+}
+
+impl AcceptAdmin<'_> {
+    pub fn apply(ctx: &mut Context<AcceptAdmin>) -> Result<()> {
+        let previous_admin = ctx.accounts.fee_config.admin;
+        ctx.accounts.fee_config.admin = ctx.accounts.pending_admin.key();
+        ctx.accounts.fee_config.pending_admin = None;
+        emit!(AdminTransferred {
+            fee_config: ctx.accounts.fee_config.key(),
+            previous_admin,
+            new_admin: ctx.accounts.fee_config.admin,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct CollectFee<'info> {
+    #[account(mut)]
+    pub from: Signer<'info>,
+    #[account(
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED, fee_config.key().as_ref()],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, fee_config.key().as_ref()],
+        bump = fee_config.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CollectFee<'_> {
+    pub fn apply(ctx: &mut Context<CollectFee>, action: FeeAction) -> Result<()> {
+        let amount =
+            ctx.accounts.fee_schedule.price_of(action).ok_or(FeeCollectorError::ActionNotPriced)?;
+        if amount == 0 {
+            return Ok(());
+        }
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.from.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(FeeCollected {
+            fee_config: ctx.accounts.fee_config.key(),
+            from: ctx.accounts.from.key(),
+            action,
+            amount,
+        });
+        Ok(())
+    }
+}
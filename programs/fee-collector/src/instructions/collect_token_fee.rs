@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct CollectTokenFee<'info> {
+    #[account(mut)]
+    pub from: Signer<'info>,
+    #[account(
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED, fee_config.key().as_ref(), token_mint.key().as_ref()],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    #[account(seeds = [VAULT_SEED, fee_config.key().as_ref()], bump = fee_config.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::authority = from,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_source: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = from,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CollectTokenFee<'_> {
+    pub fn apply(ctx: &mut Context<CollectTokenFee>, action: FeeAction) -> Result<()> {
+        let amount =
+            ctx.accounts.fee_schedule.price_of(action).ok_or(FeeCollectorError::ActionNotPriced)?;
+        if amount == 0 {
+            return Ok(());
+        }
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_source.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_vault.to_account_info(),
+                    authority: ctx.accounts.from.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        emit!(TokenFeeCollected {
+            fee_config: ctx.accounts.fee_config.key(),
+            from: ctx.accounts.from.key(),
+            mint: ctx.accounts.token_mint.key(),
+            action,
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::*;
+use anchor_lang::system_program;
+
+/// Permissionless. The fee_config is seeded by the payer, so an id can't be taken from another
+/// creator.
+#[derive(Accounts)]
+#[instruction(params: InitConfigParams)]
+pub struct InitConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeConfig::INIT_SPACE,
+        seeds = [FEE_CONFIG_SEED, payer.key().as_ref(), &params.id.to_be_bytes()],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(mut, seeds = [VAULT_SEED, fee_config.key().as_ref()], bump)]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitConfig<'_> {
+    pub fn apply(ctx: &mut Context<InitConfig>, params: &InitConfigParams) -> Result<()> {
+        ctx.accounts.fee_config.id = params.id;
+        ctx.accounts.fee_config.creator = ctx.accounts.payer.key();
+        ctx.accounts.fee_config.admin = params.admin;
+        ctx.accounts.fee_config.recipient = params.recipient;
+        ctx.accounts.fee_config.bump = ctx.bumps.fee_config;
+        ctx.accounts.fee_config.vault_bump = ctx.bumps.vault;
+        ctx.accounts.fee_config.pending_admin = None;
+
+        // fund the vault up to the rent-exempt minimum, so that any fee can be collected into it
+        let rent_exempt_lamports =
+            Rent::get()?.minimum_balance(0).saturating_sub(ctx.accounts.vault.lamports());
+        if rent_exempt_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.vault.to_account_info(),
+                    },
+                ),
+                rent_exempt_lamports,
+            )?;
+        }
+
+        emit!(ConfigUpdated {
+            fee_config: ctx.accounts.fee_config.key(),
+            admin: params.admin,
+            recipient: params.recipient,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitConfigParams {
+    pub id: u64,
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+}
@@ -0,0 +1,19 @@
+pub mod accept_admin;
+pub mod collect_fee;
+pub mod collect_token_fee;
+pub mod init_config;
+pub mod set_action_fee;
+pub mod set_config;
+pub mod set_token_action_fee;
+pub mod withdraw;
+pub mod withdraw_token;
+
+pub use accept_admin::*;
+pub use collect_fee::*;
+pub use collect_token_fee::*;
+pub use init_config::*;
+pub use set_action_fee::*;
+pub use set_config::*;
+pub use set_token_action_fee::*;
+pub use withdraw::*;
+pub use withdraw_token::*;
@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetActionFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump,
+        has_one = admin @FeeCollectorError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED, fee_config.key().as_ref()],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SetActionFee<'_> {
+    pub fn apply(ctx: &mut Context<SetActionFee>, params: &SetActionFeeParams) -> Result<()> {
+        ctx.accounts.fee_schedule.fee_config = ctx.accounts.fee_config.key();
+        ctx.accounts.fee_schedule.bump = ctx.bumps.fee_schedule;
+        ctx.accounts.fee_schedule.set_price(params.action, params.amount);
+        emit!(FeeScheduleUpdated {
+            fee_config: ctx.accounts.fee_config.key(),
+            mint: None,
+            action: params.action,
+            amount: params.amount,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetActionFeeParams {
+    pub action: FeeAction,
+    pub amount: Option<u64>, // lamports, or token units for set_token_action_fee. None removes it
+}
@@ -0,0 +1,57 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump,
+        has_one = admin @FeeCollectorError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+}
+
+impl SetConfig<'_> {
+    pub fn apply(ctx: &mut Context<SetConfig>, params: &SetConfigParams) -> Result<()> {
+        match params.clone() {
+            SetConfigParams::Admin(pending_admin) => {
+                // the new admin only takes effect once it calls accept_admin
+                ctx.accounts.fee_config.pending_admin = Some(pending_admin);
+                emit!(AdminTransferStarted {
+                    fee_config: ctx.accounts.fee_config.key(),
+                    admin: ctx.accounts.fee_config.admin,
+                    pending_admin,
+                });
+            },
+            SetConfigParams::CancelAdminTransfer => {
+                let pending_admin = ctx
+                    .accounts
+                    .fee_config
+                    .pending_admin
+                    .take()
+                    .ok_or(FeeCollectorError::NoPendingAdmin)?;
+                emit!(AdminTransferCancelled {
+                    fee_config: ctx.accounts.fee_config.key(),
+                    pending_admin,
+                });
+            },
+            SetConfigParams::Recipient(recipient) => {
+                ctx.accounts.fee_config.recipient = recipient;
+                emit!(ConfigUpdated {
+                    fee_config: ctx.accounts.fee_config.key(),
+                    admin: ctx.accounts.fee_config.admin,
+                    recipient,
+                });
+            },
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub enum SetConfigParams {
+    Admin(Pubkey), // proposes a new admin, see accept_admin
+    CancelAdminTransfer,
+    Recipient(Pubkey),
+}
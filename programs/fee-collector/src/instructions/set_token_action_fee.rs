@@ -0,0 +1,40 @@
+use crate::*;
+use anchor_spl::token_interface::Mint;
+
+/// Sets the price of an action in the token_mint, as charged by collect_token_fee.
+#[derive(Accounts)]
+pub struct SetTokenActionFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump,
+        has_one = admin @FeeCollectorError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED, fee_config.key().as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SetTokenActionFee<'_> {
+    pub fn apply(ctx: &mut Context<SetTokenActionFee>, params: &SetActionFeeParams) -> Result<()> {
+        ctx.accounts.fee_schedule.fee_config = ctx.accounts.fee_config.key();
+        ctx.accounts.fee_schedule.bump = ctx.bumps.fee_schedule;
+        ctx.accounts.fee_schedule.set_price(params.action, params.amount);
+        emit!(FeeScheduleUpdated {
+            fee_config: ctx.accounts.fee_config.key(),
+            mint: Some(ctx.accounts.token_mint.key()),
+            action: params.action,
+            amount: params.amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump,
+        has_one = admin @FeeCollectorError::Unauthorized,
+        has_one = recipient @FeeCollectorError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, fee_config.key().as_ref()],
+        bump = fee_config.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: the configured recipient
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl Withdraw<'_> {
+    pub fn apply(ctx: &mut Context<Withdraw>, amount: u64) -> Result<()> {
+        // the vault keeps its rent-exempt minimum
+        let available =
+            ctx.accounts.vault.lamports().saturating_sub(Rent::get()?.minimum_balance(0));
+        require!(amount > 0, FeeCollectorError::InvalidAmount);
+        require!(amount <= available, FeeCollectorError::InsufficientFunds);
+
+        let fee_config = ctx.accounts.fee_config.key();
+        let seeds: &[&[u8]] =
+            &[VAULT_SEED, fee_config.as_ref(), &[ctx.accounts.fee_config.vault_bump]];
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+            )
+            .with_signer(&[seeds]),
+            amount,
+        )?;
+
+        emit!(FeeWithdrawn {
+            fee_config,
+            recipient: ctx.accounts.recipient.key(),
+            mint: None,
+            amount
+        });
+        Ok(())
+    }
+}
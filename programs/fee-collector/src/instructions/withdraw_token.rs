@@ -0,0 +1,69 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [FEE_CONFIG_SEED, fee_config.creator.as_ref(), &fee_config.id.to_be_bytes()],
+        bump = fee_config.bump,
+        has_one = admin @FeeCollectorError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(seeds = [VAULT_SEED, fee_config.key().as_ref()], bump = fee_config.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::authority = fee_config.recipient,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl WithdrawToken<'_> {
+    pub fn apply(ctx: &mut Context<WithdrawToken>, amount: u64) -> Result<()> {
+        require!(amount > 0, FeeCollectorError::InvalidAmount);
+        require!(amount <= ctx.accounts.token_vault.amount, FeeCollectorError::InsufficientFunds);
+
+        let fee_config = ctx.accounts.fee_config.key();
+        let seeds: &[&[u8]] =
+            &[VAULT_SEED, fee_config.as_ref(), &[ctx.accounts.fee_config.vault_bump]];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+            )
+            .with_signer(&[seeds]),
+            amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        emit!(FeeWithdrawn {
+            fee_config,
+            recipient: ctx.accounts.fee_config.recipient,
+            mint: Some(ctx.accounts.token_mint.key()),
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -1,59 +1,64 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::system_instruction;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+
+use errors::*;
+use events::*;
+use instructions::*;
+use state::*;
 
 declare_id!("DM9ddjxyyqHrQDChNSkuhW7gMHKJgutCeHPej2oTGXPW");
 
+pub const FEE_CONFIG_SEED: &[u8] = b"FeeConfig";
+pub const VAULT_SEED: &[u8] = b"Vault";
+pub const FEE_SCHEDULE_SEED: &[u8] = b"FeeSchedule";
+
 #[program]
 pub mod fee_collector {
     use super::*;
 
-    pub fn collect_fee(
-        ctx: Context<CollectFee>,
-        amount: u64,
+    pub fn init_config(mut ctx: Context<InitConfig>, params: InitConfigParams) -> Result<()> {
+        InitConfig::apply(&mut ctx, &params)
+    }
+
+    pub fn set_config(mut ctx: Context<SetConfig>, params: SetConfigParams) -> Result<()> {
+        SetConfig::apply(&mut ctx, &params)
+    }
+
+    pub fn accept_admin(mut ctx: Context<AcceptAdmin>) -> Result<()> {
+        AcceptAdmin::apply(&mut ctx)
+    }
+
+    pub fn set_action_fee(
+        mut ctx: Context<SetActionFee>,
+        params: SetActionFeeParams,
     ) -> Result<()> {
-        // Create the transfer instruction
-        let transfer_instruction = system_instruction::transfer(
-            &ctx.accounts.from.key(),
-            &ctx.accounts.to.key(),
-            amount,
-        );
-
-        // Execute the transfer
-        anchor_lang::solana_program::program::invoke(
-            &transfer_instruction,
-            &[
-                ctx.accounts.from.to_account_info(),
-                ctx.accounts.to.to_account_info(),
-            ],
-        )?;
-
-        // Emit event for tracking
-        emit!(FeeCollected {
-            from: ctx.accounts.from.key(),
-            to: ctx.accounts.to.key(),
-            amount,
-        });
-
-        Ok(())
+        SetActionFee::apply(&mut ctx, &params)
     }
-}
 
-#[derive(Accounts)]
-pub struct CollectFee<'info> {
-    /// CHECK: This is the account that will pay the fee
-    #[account(mut)]
-    pub from: Signer<'info>,
-    
-    /// CHECK: This is the account that will receive the fee
-    #[account(mut)]
-    pub to: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    pub fn set_token_action_fee(
+        mut ctx: Context<SetTokenActionFee>,
+        params: SetActionFeeParams,
+    ) -> Result<()> {
+        SetTokenActionFee::apply(&mut ctx, &params)
+    }
+
+    pub fn collect_fee(mut ctx: Context<CollectFee>, action: FeeAction) -> Result<()> {
+        CollectFee::apply(&mut ctx, action)
+    }
 
-#[event]
-pub struct FeeCollected {
-    pub from: Pubkey,
-    pub to: Pubkey,
-    pub amount: u64,
+    pub fn collect_token_fee(mut ctx: Context<CollectTokenFee>, action: FeeAction) -> Result<()> {
+        CollectTokenFee::apply(&mut ctx, action)
+    }
+
+    pub fn withdraw(mut ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        Withdraw::apply(&mut ctx, amount)
+    }
+
+    pub fn withdraw_token(mut ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+        WithdrawToken::apply(&mut ctx, amount)
+    }
 }
@@ -0,0 +1,18 @@
+use crate::*;
+
+/// FeeConfig owns a vault PDA that fees are collected into. The vault holds SOL itself and
+/// is the authority of the token vaults, the associated token accounts of the vault.
+/// Withdrawals can only go to the configured recipient. The admin is transferred in two steps,
+/// a new admin is proposed with set_config and takes effect once it calls accept_admin.
+/// Seeded by the creator and the id, the creator doesn't hold any authority over it.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeConfig {
+    pub id: u64,
+    pub creator: Pubkey,
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub pending_admin: Option<Pubkey>, // set by the admin, must be accepted by the pending admin
+}
@@ -0,0 +1,44 @@
+use crate::*;
+
+pub const MAX_FEE_ACTIONS: usize = 16;
+
+/// FeeSchedule holds the price of each action of a fee_config, in lamports for the schedule
+/// seeded by the fee_config only, or in the smallest unit of the token for the schedule seeded
+/// by the fee_config and a token mint. collect_fee and collect_token_fee fail for actions
+/// without a price.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSchedule {
+    pub fee_config: Pubkey,
+    #[max_len(MAX_FEE_ACTIONS)]
+    pub fees: Vec<ActionFee>,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ActionFee {
+    pub action: FeeAction,
+    pub amount: u64,
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug)]
+pub enum FeeAction {
+    TokenCreation,
+    Bridge,
+    PeerSetup,
+    ConfigUpdate,
+}
+
+impl FeeSchedule {
+    pub fn price_of(&self, action: FeeAction) -> Option<u64> {
+        self.fees.iter().find(|fee| fee.action == action).map(|fee| fee.amount)
+    }
+
+    /// None amount removes the price of the action.
+    pub fn set_price(&mut self, action: FeeAction, amount: Option<u64>) {
+        self.fees.retain(|fee| fee.action != action);
+        if let Some(amount) = amount {
+            self.fees.push(ActionFee { action, amount });
+        }
+    }
+}
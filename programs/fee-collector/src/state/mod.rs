@@ -0,0 +1,5 @@
+pub mod fee_config;
+pub mod fee_schedule;
+
+pub use fee_config::*;
+pub use fee_schedule::*;
@@ -11,4 +11,42 @@ pub enum OFTError {
     InvalidFee,
     InvalidMintAuthority,
     Paused,
+    NoPendingAdmin,
+    TimelockRequired,
+    TimelockNotElapsed,
+    InvalidProposal,
+    ProposalNotApproved,
+    AlreadyApproved,
+    InvalidApprovers,
+    SenderRateLimiterRequired,
+    InvalidRateLimiter,
+    AmountBelowMinimum,
+    AmountAboveMaximum,
+    InvalidSendAmountBounds,
+    InvalidFeeExemption,
+    InvalidFeeSplit,
+    MathOverflow,
+    NoSurplus,
+    InvalidReferrer,
+    LamportFeeVaultRequired,
+    LaunchpadFeeAccountsRequired,
+    InvalidTokenProgram,
+    AddressBlocked,
+    BlocklistAccountsRequired,
+    InvalidAmount,
+    ComposeToTokenAccount,
+    TokenSourceRequired,
+    WrappedNativeAccountsRequired,
+    InvalidNativeMint,
+    TransferFeeNotSupported,
+    AlreadyMigrated,
+    ComposeWithInboundFee,
+    EscrowBelowReserved,
+    NothingToClaim,
+    ReferralNotSupported,
+    MetadataRequiresToken2022,
+    InvalidRecoveryClaimTimeout,
+    RecoveryClaimRequired,
+    ToTokenAccountNotSupported,
+    LaunchpadFeeNotPriced,
 }
@@ -7,6 +7,7 @@ pub struct OFTSent {
     pub from: Pubkey,
     pub amount_sent_ld: u64,
     pub amount_received_ld: u64,
+    pub referrer: Option<Pubkey>,
 }
 
 #[event]
@@ -15,4 +16,148 @@ pub struct OFTReceived {
     pub src_eid: u32,
     pub to: Pubkey,
     pub amount_received_ld: u64,
+    pub inbound_fee_ld: u64,
+}
+
+#[event]
+pub struct AdminTransferStarted {
+    pub oft_store: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub oft_store: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub oft_store: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub oft_store: Pubkey,
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub oft_store: Pubkey,
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub oft_store: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub eta: u64,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub oft_store: Pubkey,
+    pub id: u64,
+    pub approver: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub oft_store: Pubkey,
+    pub id: u64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub oft_store: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct FeeExemptionAdded {
+    pub oft_store: Pubkey,
+    pub wallet: Pubkey,
+    pub dst_eid: u32,
+}
+
+#[event]
+pub struct FeeExemptionRemoved {
+    pub oft_store: Pubkey,
+    pub wallet: Pubkey,
+    pub dst_eid: u32,
+}
+
+#[event]
+pub struct FeesDistributed {
+    pub oft_store: Pubkey,
+    pub fee_ld: u64,
+}
+
+#[event]
+pub struct SurplusSwept {
+    pub oft_store: Pubkey,
+    pub token_dest: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub oft_store: Pubkey,
+    pub referrer: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct AddressBlocked {
+    pub oft_store: Pubkey,
+    pub address: [u8; 32],
+    pub reason_code: Option<u16>,
+}
+
+#[event]
+pub struct AddressUnblocked {
+    pub oft_store: Pubkey,
+    pub address: [u8; 32],
+}
+
+#[event]
+pub struct OFTQuarantined {
+    pub guid: [u8; 32],
+    pub src_eid: u32,
+    pub to: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct QuarantineReleased {
+    pub oft_store: Pubkey,
+    pub guid: [u8; 32],
+    pub token_dest: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct OFTRecovered {
+    pub guid: [u8; 32],
+    pub src_eid: u32,
+    pub to: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct RecoveryClaimed {
+    pub oft_store: Pubkey,
+    pub guid: [u8; 32],
+    pub claimer: Pubkey,
+    pub token_dest: Pubkey,
+    pub amount_ld: u64,
 }
@@ -0,0 +1,27 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = oft_store.pending_admin == Some(pending_admin.key()) @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+}
+
+impl AcceptAdmin<'_> {
+    pub fn apply(ctx: &mut Context<AcceptAdmin>) -> Result<()> {
+        let previous_admin = ctx.accounts.oft_store.admin;
+        ctx.accounts.oft_store.admin = ctx.accounts.pending_admin.key();
+        ctx.accounts.oft_store.pending_admin = None;
+        emit!(AdminTransferred {
+            oft_store: ctx.accounts.oft_store.key(),
+            previous_admin,
+            new_admin: ctx.accounts.oft_store.admin,
+        });
+        Ok(())
+    }
+}
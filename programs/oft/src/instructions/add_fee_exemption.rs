@@ -0,0 +1,56 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: AddFeeExemptionParams)]
+pub struct AddFeeExemption<'info> {
+    /// admin or a fee manager
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = is_admin_or_role_holder(
+            &oft_store,
+            signer.key(),
+            &role_assignment,
+            Some(Role::FeeManager)
+        ) @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FeeExemption::INIT_SPACE,
+        seeds = [
+            FEE_EXEMPTION_SEED,
+            oft_store.key().as_ref(),
+            params.wallet.as_ref(),
+            &params.dst_eid.to_be_bytes()
+        ],
+        bump
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+    pub system_program: Program<'info, System>,
+}
+
+impl AddFeeExemption<'_> {
+    pub fn apply(ctx: &mut Context<AddFeeExemption>, params: &AddFeeExemptionParams) -> Result<()> {
+        ctx.accounts.fee_exemption.oft_store = ctx.accounts.oft_store.key();
+        ctx.accounts.fee_exemption.wallet = params.wallet;
+        ctx.accounts.fee_exemption.dst_eid = params.dst_eid;
+        ctx.accounts.fee_exemption.bump = ctx.bumps.fee_exemption;
+        emit!(FeeExemptionAdded {
+            oft_store: ctx.accounts.oft_store.key(),
+            wallet: params.wallet,
+            dst_eid: params.dst_eid,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AddFeeExemptionParams {
+    pub wallet: Pubkey,
+    pub dst_eid: u32, // 0 exempts the wallet towards every peer
+}
@@ -0,0 +1,35 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub approver: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = oft_store.approvers.contains(&approver.key()) @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, oft_store.key().as_ref(), &proposal.id.to_be_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl ApproveProposal<'_> {
+    pub fn apply(ctx: &mut Context<ApproveProposal>) -> Result<()> {
+        let approver = ctx.accounts.approver.key();
+        require!(!ctx.accounts.proposal.approvals.contains(&approver), OFTError::AlreadyApproved);
+        // drop approvals of rotated out approvers, which don't count and would overflow the list
+        let approvers = &ctx.accounts.oft_store.approvers;
+        ctx.accounts.proposal.approvals.retain(|approval| approvers.contains(approval));
+        ctx.accounts.proposal.approvals.push(approver);
+        emit!(ProposalApproved {
+            oft_store: ctx.accounts.oft_store.key(),
+            id: ctx.accounts.proposal.id,
+            approver,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: BlockAddressParams)]
+pub struct BlockAddress<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + BlockedAddress::INIT_SPACE,
+        seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), &params.address],
+        bump
+    )]
+    pub blocked_address: Account<'info, BlockedAddress>,
+    pub system_program: Program<'info, System>,
+}
+
+impl BlockAddress<'_> {
+    pub fn apply(ctx: &mut Context<BlockAddress>, params: &BlockAddressParams) -> Result<()> {
+        ctx.accounts.blocked_address.oft_store = ctx.accounts.oft_store.key();
+        ctx.accounts.blocked_address.address = params.address;
+        ctx.accounts.blocked_address.reason_code = params.reason_code;
+        ctx.accounts.blocked_address.bump = ctx.bumps.blocked_address;
+        emit!(AddressBlocked {
+            oft_store: ctx.accounts.oft_store.key(),
+            address: params.address,
+            reason_code: params.reason_code,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BlockAddressParams {
+    pub address: [u8; 32],
+    pub reason_code: Option<u16>,
+}
@@ -0,0 +1,38 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    /// the admin or the proposer
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer @OFTError::InvalidProposal,
+        seeds = [PROPOSAL_SEED, oft_store.key().as_ref(), &proposal.id.to_be_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: refunded the rent of the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+impl CancelProposal<'_> {
+    pub fn apply(ctx: &mut Context<CancelProposal>) -> Result<()> {
+        let signer = ctx.accounts.signer.key();
+        require!(
+            signer == ctx.accounts.oft_store.admin || signer == ctx.accounts.proposal.proposer,
+            OFTError::Unauthorized
+        );
+        emit!(ProposalCancelled {
+            oft_store: ctx.accounts.oft_store.key(),
+            id: ctx.accounts.proposal.id,
+        });
+        Ok(())
+    }
+}
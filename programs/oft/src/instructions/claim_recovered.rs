@@ -0,0 +1,97 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+/// Pays out a RecoveryClaim from token_escrow. Claims of WrappedNative are paid in wSOL, which
+/// the claimer can unwrap by closing token_dest.
+#[derive(Accounts)]
+pub struct ClaimRecovered<'info> {
+    /// the recipient, or the admin once the recovery_claim_timeout has elapsed. Anyone if the
+    /// recipient is a token account and it's the token_dest
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [RECOVERY_CLAIM_SEED, oft_store.key().as_ref(), &recovery_claim.guid],
+        bump = recovery_claim.bump
+    )]
+    pub recovery_claim: Account<'info, RecoveryClaim>,
+    /// CHECK: refunded the rent of the recovery_claim
+    #[account(mut, address = recovery_claim.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimRecovered<'_> {
+    pub fn apply(ctx: &mut Context<ClaimRecovered>) -> Result<()> {
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        require!(
+            ctx.accounts.token_dest.key() == ctx.accounts.recovery_claim.recipient
+                || ctx.accounts.recovery_claim.can_claim(
+                    &ctx.accounts.oft_store,
+                    ctx.accounts.signer.key(),
+                    current_time
+                ),
+            OFTError::Unauthorized
+        );
+        let amount_ld = ctx.accounts.recovery_claim.amount_ld;
+        ctx.accounts.oft_store.claimable_ld = ctx
+            .accounts
+            .oft_store
+            .claimable_ld
+            .checked_sub(amount_ld)
+            .ok_or(OFTError::MathOverflow)?;
+
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            &ctx.accounts.token_escrow.key().to_bytes(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_escrow.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.oft_store.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds]),
+            amount_ld,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        emit!(RecoveryClaimed {
+            oft_store: ctx.accounts.oft_store.key(),
+            guid: ctx.accounts.recovery_claim.guid,
+            claimer: ctx.accounts.signer.key(),
+            token_dest: ctx.accounts.token_dest.key(),
+            amount_ld,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED, oft_store.key().as_ref(), wallet.key().as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+    #[account(
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimReferralRewards<'_> {
+    pub fn apply(ctx: &mut Context<ClaimReferralRewards>) -> Result<()> {
+        let amount_ld = ctx.accounts.referrer.accrued_ld;
+        require!(amount_ld > 0, OFTError::NothingToClaim);
+        ctx.accounts.referrer.accrued_ld = 0;
+        ctx.accounts.oft_store.accrued_referral_ld = ctx
+            .accounts
+            .oft_store
+            .accrued_referral_ld
+            .checked_sub(amount_ld)
+            .ok_or(OFTError::MathOverflow)?;
+
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            &ctx.accounts.token_escrow.key().to_bytes(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_escrow.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.oft_store.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds]),
+            amount_ld,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        emit!(ReferralRewardsClaimed {
+            oft_store: ctx.accounts.oft_store.key(),
+            referrer: ctx.accounts.wallet.key(),
+            amount_ld,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: CreateProposalParams)]
+pub struct CreateProposal<'info> {
+    /// admin or a holder of the role required by the action. Approvers can only approve
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = is_admin_or_role_holder(
+            &oft_store,
+            signer.key(),
+            &role_assignment,
+            params.action.required_role()
+        ) @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, oft_store.key().as_ref(), &params.id.to_be_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateProposal<'_> {
+    pub fn apply(ctx: &mut Context<CreateProposal>, params: &CreateProposalParams) -> Result<()> {
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        ctx.accounts.proposal.oft_store = ctx.accounts.oft_store.key();
+        ctx.accounts.proposal.id = params.id;
+        ctx.accounts.proposal.proposer = ctx.accounts.signer.key();
+        ctx.accounts.proposal.action = params.action.clone();
+        ctx.accounts.proposal.eta =
+            current_time.saturating_add(ctx.accounts.oft_store.timelock_delay);
+        ctx.accounts.proposal.bump = ctx.bumps.proposal;
+        emit!(ProposalCreated {
+            oft_store: ctx.accounts.oft_store.key(),
+            id: params.id,
+            proposer: ctx.accounts.signer.key(),
+            action: params.action.clone(),
+            eta: ctx.accounts.proposal.eta,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateProposalParams {
+    pub id: u64,
+    pub action: AdminAction,
+}
@@ -0,0 +1,80 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+/// Permissionless. The token accounts of the fee recipients are passed as remaining accounts,
+/// in the order of oft_store.fee_recipients, and must hold token_mint under token_program.
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl DistributeFees<'_> {
+    pub fn apply<'info>(ctx: &mut Context<'_, '_, '_, 'info, DistributeFees<'info>>) -> Result<()> {
+        let fee_recipients = ctx.accounts.oft_store.fee_recipients.clone();
+        require!(
+            !fee_recipients.is_empty() && ctx.remaining_accounts.len() == fee_recipients.len(),
+            OFTError::InvalidFeeSplit
+        );
+        let fee_ld = ctx.accounts.oft_store.accrued_fee_ld;
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            &ctx.accounts.token_escrow.key().to_bytes(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        for (recipient, token_dest) in fee_recipients.iter().zip(ctx.remaining_accounts.iter()) {
+            require!(token_dest.key() == recipient.token_account, OFTError::InvalidFeeSplit);
+            // the recipients are remaining accounts, so check what token::mint and
+            // token::token_program would check on a declared account
+            require!(
+                token_dest.owner == ctx.accounts.token_program.key,
+                OFTError::InvalidTokenDest
+            );
+            let token_account =
+                TokenAccount::try_deserialize(&mut &token_dest.try_borrow_data()?[..])?;
+            require!(
+                token_account.mint == ctx.accounts.token_mint.key(),
+                OFTError::InvalidTokenDest
+            );
+            let amount_ld = recipient.share_of(fee_ld);
+            if amount_ld == 0 {
+                continue;
+            }
+            ctx.accounts.oft_store.take_fee(amount_ld)?;
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.token_escrow.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: token_dest.clone(),
+                        authority: ctx.accounts.oft_store.to_account_info(),
+                    },
+                )
+                .with_signer(&[&seeds]),
+                amount_ld,
+                ctx.accounts.token_mint.decimals,
+            )?;
+        }
+        emit!(FeesDistributed { oft_store: ctx.accounts.oft_store.key(), fee_ld });
+        Ok(())
+    }
+}
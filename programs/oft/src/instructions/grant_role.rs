@@ -0,0 +1,49 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: GrantRoleParams)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [
+            ROLE_SEED,
+            oft_store.key().as_ref(),
+            &[params.role as u8],
+            params.holder.as_ref()
+        ],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+    pub system_program: Program<'info, System>,
+}
+
+impl GrantRole<'_> {
+    pub fn apply(ctx: &mut Context<GrantRole>, params: &GrantRoleParams) -> Result<()> {
+        ctx.accounts.role_assignment.oft_store = ctx.accounts.oft_store.key();
+        ctx.accounts.role_assignment.holder = params.holder;
+        ctx.accounts.role_assignment.role = params.role;
+        ctx.accounts.role_assignment.bump = ctx.bumps.role_assignment;
+        emit!(RoleGranted {
+            oft_store: ctx.accounts.oft_store.key(),
+            role: params.role,
+            holder: params.holder,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct GrantRoleParams {
+    pub role: Role,
+    pub holder: Pubkey,
+}
@@ -1,5 +1,9 @@
 use crate::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::{
+    token::spl_token,
+    token_2022::spl_token_2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 use oapp::endpoint::{instructions::RegisterOAppParams, ID as ENDPOINT_ID};
 
 #[derive(Accounts)]
@@ -38,45 +42,96 @@ pub struct InitOFT<'info> {
 
 impl InitOFT<'_> {
     pub fn apply(ctx: &mut Context<InitOFT>, params: &InitOFTParams) -> Result<()> {
-        // Initialize the oft_store
-        ctx.accounts.oft_store.oft_type = params.oft_type.clone();
-        require!(
-            ctx.accounts.token_mint.decimals >= params.shared_decimals,
-            OFTError::InvalidDecimals
-        );
-        ctx.accounts.oft_store.ld2sd_rate =
-            10u64.pow((ctx.accounts.token_mint.decimals - params.shared_decimals) as u32);
-        ctx.accounts.oft_store.token_mint = ctx.accounts.token_mint.key();
-        ctx.accounts.oft_store.token_escrow = ctx.accounts.token_escrow.key();
-        ctx.accounts.oft_store.endpoint_program =
-            if let Some(endpoint_program) = params.endpoint_program {
-                endpoint_program
-            } else {
-                ENDPOINT_ID
-            };
-        ctx.accounts.oft_store.bump = ctx.bumps.oft_store;
-        ctx.accounts.oft_store.tvl_ld = 0;
-        ctx.accounts.oft_store.admin = params.admin;
-        ctx.accounts.oft_store.default_fee_bps = 0;
-        ctx.accounts.oft_store.paused = false;
-        ctx.accounts.oft_store.pauser = None;
-        ctx.accounts.oft_store.unpauser = None;
-
         // Initialize the lz_receive_types_accounts
         ctx.accounts.lz_receive_types_accounts.oft_store = ctx.accounts.oft_store.key();
         ctx.accounts.lz_receive_types_accounts.token_mint = ctx.accounts.token_mint.key();
 
-        // Register the oapp
-        oapp::endpoint_cpi::register_oapp(
-            ctx.accounts.oft_store.endpoint_program,
-            ctx.accounts.oft_store.key(),
+        // Hybrid locks and burns the same amount it receives, which a transfer fee breaks
+        if params.oft_type == OFTType::Hybrid {
+            require!(
+                !has_transfer_fee(&ctx.accounts.token_mint)?,
+                OFTError::TransferFeeNotSupported
+            );
+        }
+
+        init_oft_store(
+            &mut ctx.accounts.oft_store,
+            ctx.accounts.token_mint.key(),
+            ctx.accounts.token_mint.decimals,
+            ctx.accounts.token_escrow.key(),
+            ctx.bumps.oft_store,
+            params,
             ctx.remaining_accounts,
-            &[OFT_SEED, ctx.accounts.token_escrow.key().as_ref(), &[ctx.bumps.oft_store]],
-            RegisterOAppParams { delegate: params.admin },
         )
     }
 }
 
+/// Initializes the oft_store and registers it as an oapp on the endpoint.
+/// Shared by init_oft and launch_token.
+pub(crate) fn init_oft_store(
+    oft_store: &mut Account<OFTStore>,
+    token_mint: Pubkey,
+    decimals: u8,
+    token_escrow: Pubkey,
+    bump: u8,
+    params: &InitOFTParams,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    // Initialize the oft_store
+    if params.oft_type == OFTType::WrappedNative {
+        require!(
+            token_mint == spl_token::native_mint::ID
+                || token_mint == spl_token_2022::native_mint::ID,
+            OFTError::InvalidNativeMint
+        );
+    }
+    oft_store.oft_type = params.oft_type.clone();
+    require!(decimals >= params.shared_decimals, OFTError::InvalidDecimals);
+    oft_store.ld2sd_rate = 10u64.pow((decimals - params.shared_decimals) as u32);
+    oft_store.token_mint = token_mint;
+    oft_store.token_escrow = token_escrow;
+    oft_store.endpoint_program = if let Some(endpoint_program) = params.endpoint_program {
+        endpoint_program
+    } else {
+        ENDPOINT_ID
+    };
+    oft_store.bump = bump;
+    oft_store.tvl_ld = 0;
+    oft_store.accrued_fee_ld = 0;
+    oft_store.accrued_referral_ld = 0;
+    oft_store.quarantined_ld = 0;
+    oft_store.claimable_ld = 0;
+    oft_store.admin = params.admin;
+    oft_store.pending_admin = None;
+    oft_store.default_fee_bps = 0;
+    oft_store.default_fee_schedule = None;
+    oft_store.lamport_fee_config = None;
+    oft_store.launchpad_fee = None;
+    oft_store.paused = false;
+    oft_store.blocklist_enabled = false;
+    oft_store.pauser = None;
+    oft_store.unpauser = None;
+    oft_store.version = OFT_STORE_VERSION;
+    oft_store.timelock_delay = 0;
+    oft_store.recovery_claim_timeout = DEFAULT_RECOVERY_CLAIM_TIMEOUT;
+    oft_store.approvers = vec![];
+    oft_store.approval_threshold = 0;
+    oft_store.outbound_rate_limiter = None;
+    oft_store.inbound_rate_limiter = None;
+    oft_store.fee_recipients = vec![];
+    oft_store.referral_fee_share_bps = 0;
+    oft_store.lock_cap_ld = 0;
+
+    // Register the oapp
+    oapp::endpoint_cpi::register_oapp(
+        oft_store.endpoint_program,
+        oft_store.key(),
+        remaining_accounts,
+        &[OFT_SEED, token_escrow.as_ref(), &[bump]],
+        RegisterOAppParams { delegate: params.admin },
+    )
+}
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct InitOFTParams {
     pub oft_type: OFTType,
@@ -0,0 +1,250 @@
+use crate::*;
+use anchor_lang::{
+    solana_program::{self, program_pack::Pack},
+    system_program,
+};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_2022::spl_token_2022::{
+        self,
+        extension::{metadata_pointer, ExtensionType},
+        state::{Account as TokenAccountState, Mint as MintState},
+    },
+    token_interface::{self, InitializeAccount3, InitializeMint2, MintTo, TokenInterface},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+/// Creates the token mint with the oft_store as mint authority, its metadata and a Native
+/// oft_store in one transaction, so that a failed launch can't leave an orphan mint.
+/// The metadata is stored in the mint with the Token-2022 metadata extension, and its update
+/// authority is the admin. SPL Token mints are launched without metadata, Metaplex metadata
+/// can be added to them afterwards as the oft_store holds no metadata authority.
+#[derive(Accounts)]
+#[instruction(params: LaunchTokenParams)]
+pub struct LaunchToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OFTStore::INIT_SPACE,
+        seeds = [OFT_SEED, token_escrow.key().as_ref()],
+        bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LzReceiveTypesAccounts::INIT_SPACE,
+        seeds = [LZ_RECEIVE_TYPES_SEED, oft_store.key().as_ref()],
+        bump
+    )]
+    pub lz_receive_types_accounts: Account<'info, LzReceiveTypesAccounts>,
+    #[account(mut)]
+    pub token_mint: Signer<'info>,
+    #[account(mut)]
+    pub token_escrow: Signer<'info>,
+    /// CHECK: the associated token account of the payer, only required with an initial supply.
+    /// Validated by the associated token program.
+    /// CHECK: the admin, set as the metadata update authority. Only read by the token program.
+    #[account(address = params.admin @OFTError::Unauthorized)]
+    pub update_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub creator_token_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl LaunchToken<'_> {
+    pub fn apply(ctx: &mut Context<LaunchToken>, params: &LaunchTokenParams) -> Result<()> {
+        let oft_store = ctx.accounts.oft_store.key();
+        let token_mint = ctx.accounts.token_mint.key();
+        let token_program = ctx.accounts.token_program.key();
+        let seeds: &[&[u8]] =
+            &[OFT_SEED, ctx.accounts.token_escrow.key.as_ref(), &[ctx.bumps.oft_store]];
+
+        // Create the mint, with room for the metadata on Token-2022
+        let (space, metadata) = match &params.metadata {
+            Some(metadata) => {
+                require!(token_program == spl_token_2022::ID, OFTError::MetadataRequiresToken2022);
+                let metadata = TokenMetadata {
+                    update_authority: Some(params.admin).try_into()?,
+                    mint: token_mint,
+                    name: metadata.name.clone(),
+                    symbol: metadata.symbol.clone(),
+                    uri: metadata.uri.clone(),
+                    additional_metadata: vec![],
+                };
+                let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+                    ExtensionType::MetadataPointer,
+                ])?;
+                (space, Some(metadata))
+            },
+            None => (MintState::LEN, None),
+        };
+        let metadata_space = match &metadata {
+            Some(metadata) => metadata.tlv_size_of()?,
+            None => 0,
+        };
+        // the token program reallocs the mint for the metadata, but it must be funded upfront
+        let lamports = Rent::get()?.minimum_balance(space + metadata_space);
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.token_mint.to_account_info(),
+                },
+            ),
+            lamports,
+            space as u64,
+            &token_program,
+        )?;
+        if metadata.is_some() {
+            let ix = metadata_pointer::instruction::initialize(
+                &token_program,
+                &token_mint,
+                Some(params.admin),
+                Some(token_mint),
+            )?;
+            solana_program::program::invoke(&ix, &[ctx.accounts.token_mint.to_account_info()])?;
+        }
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InitializeMint2 { mint: ctx.accounts.token_mint.to_account_info() },
+            ),
+            params.decimals,
+            &oft_store,
+            None,
+        )?;
+        if let Some(metadata) = metadata {
+            initialize_metadata(
+                &token_program,
+                &ctx.accounts.token_mint.to_account_info(),
+                &ctx.accounts.update_authority.to_account_info(),
+                &ctx.accounts.oft_store.to_account_info(),
+                metadata,
+                seeds,
+            )?;
+        }
+
+        // Create the escrow
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.token_escrow.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(TokenAccountState::LEN),
+            TokenAccountState::LEN as u64,
+            &token_program,
+        )?;
+        token_interface::initialize_account3(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: ctx.accounts.token_escrow.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                authority: ctx.accounts.oft_store.to_account_info(),
+            },
+        ))?;
+
+        // Mint the initial supply to the payer
+        if params.initial_supply_ld > 0 {
+            let (Some(creator_token_account), Some(associated_token_program)) =
+                (&ctx.accounts.creator_token_account, &ctx.accounts.associated_token_program)
+            else {
+                return Err(OFTError::InvalidTokenDest.into());
+            };
+            associated_token::create(CpiContext::new(
+                associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    associated_token: creator_token_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+            token_interface::mint_to(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: creator_token_account.to_account_info(),
+                        authority: ctx.accounts.oft_store.to_account_info(),
+                    },
+                )
+                .with_signer(&[seeds]),
+                params.initial_supply_ld,
+            )?;
+        }
+
+        // Initialize the lz_receive_types_accounts
+        ctx.accounts.lz_receive_types_accounts.oft_store = oft_store;
+        ctx.accounts.lz_receive_types_accounts.token_mint = token_mint;
+
+        init_oft_store(
+            &mut ctx.accounts.oft_store,
+            token_mint,
+            params.decimals,
+            ctx.accounts.token_escrow.key(),
+            ctx.bumps.oft_store,
+            &InitOFTParams {
+                oft_type: OFTType::Native,
+                admin: params.admin,
+                shared_decimals: params.shared_decimals,
+                endpoint_program: params.endpoint_program,
+            },
+            ctx.remaining_accounts,
+        )
+    }
+}
+
+/// Initializes the metadata stored in the mint, signed by the oft_store as mint authority.
+pub fn initialize_metadata<'info>(
+    token_program: &Pubkey,
+    token_mint: &AccountInfo<'info>,
+    update_authority: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    metadata: TokenMetadata,
+    mint_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let ix = spl_token_metadata_interface::instruction::initialize(
+        token_program,
+        token_mint.key,
+        update_authority.key,
+        token_mint.key,
+        mint_authority.key,
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    );
+    solana_program::program::invoke_signed(
+        &ix,
+        &[token_mint.clone(), update_authority.clone(), mint_authority.clone()],
+        &[mint_authority_seeds],
+    )?;
+    Ok(())
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct LaunchTokenParams {
+    pub decimals: u8,
+    pub shared_decimals: u8,
+    pub admin: Pubkey,
+    pub endpoint_program: Option<Pubkey>,
+    pub metadata: Option<LaunchTokenMetadata>, // Token-2022 only, updatable by the admin
+    pub initial_supply_ld: u64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct LaunchTokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
@@ -1,9 +1,9 @@
 use crate::*;
-use anchor_lang::solana_program;
+use anchor_lang::{solana_program, system_program, Space};
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
     token_2022::spl_token_2022::{self, solana_program::program_option::COption},
-    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 use oapp::endpoint::{
     cpi::accounts::Clear,
@@ -42,24 +42,22 @@ pub struct LzReceive<'info> {
         token::token_program = token_program
     )]
     pub token_escrow: InterfaceAccount<'info, TokenAccount>,
-    /// CHECK: the wallet address to receive the token
+    /// CHECK: the wallet address to receive the token, the owner of the token account if the
+    /// message carries one. Not mut as it's read-only for other types, lz_receive_types marks it
+    /// writable for WrappedNative, where it receives lamports, checked in unwrap_to_address
     #[account(address = Pubkey::from(msg_codec::send_to(&params.message)) @OFTError::InvalidTokenDest)]
     pub to_address: AccountInfo<'info>,
-    #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = token_mint,
-        associated_token::authority = to_address,
-        associated_token::token_program = token_program
-    )]
-    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: validated in validate_token_dest. The token account of the message, or the
+    /// associated token account of to_address, created if needed. Unused for WrappedNative
+    #[account(mut)]
+    pub token_dest: UncheckedAccount<'info>,
     #[account(
         mut,
         address = oft_store.token_mint,
         mint::token_program = token_program
     )]
     pub token_mint: InterfaceAccount<'info, Mint>,
-    // Only used for native mint, the mint authority can be:
+    // Only used for native and hybrid mints, the mint authority can be:
     //      1. a spl-token multisig account with oft_store as one of the signers, and the quorum **MUST** be 1-of-n. (recommended)
     //      2. or the mint_authority is oft_store itself.
     #[account(constraint = token_mint.mint_authority == COption::Some(mint_authority.key()) @OFTError::InvalidMintAuthority)]
@@ -67,6 +65,27 @@ pub struct LzReceive<'info> {
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    /// CHECK: the BlockedAddress PDA of the recipient. If it exists, the tokens are quarantined
+    #[account(seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), to_address.key().as_ref()], bump)]
+    pub to_blocklist: UncheckedAccount<'info>,
+    // Only required for WrappedNative, a temporary wSOL account that is closed to unwrap
+    #[account(
+        init,
+        payer = payer,
+        seeds = [UNWRAP_SEED, oft_store.key().as_ref()],
+        bump,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: the QuarantineRecord PDA of the message, only created if the tokens are quarantined
+    #[account(mut, seeds = [QUARANTINE_SEED, oft_store.key().as_ref(), &params.guid], bump)]
+    pub quarantine_record: UncheckedAccount<'info>,
+    /// CHECK: the RecoveryClaim PDA of the message, only required for WrappedNative. Created if
+    /// the amount can't be paid out to to_address
+    #[account(mut, seeds = [RECOVERY_CLAIM_SEED, oft_store.key().as_ref(), &params.guid], bump)]
+    pub recovery_claim: Option<UncheckedAccount<'info>>,
 }
 
 impl LzReceive<'_> {
@@ -93,22 +112,58 @@ impl LzReceive<'_> {
             },
         )?;
 
+        if ctx.accounts.oft_store.oft_type != OFTType::WrappedNative {
+            ctx.accounts.validate_token_dest(&params.message)?;
+        }
+
         // Convert the amount from sd to ld
         let amount_sd = msg_codec::amount_sd(&params.message);
         let mut amount_received_ld = ctx.accounts.oft_store.sd2ld(amount_sd);
 
-        // Consume the inbound rate limiter
-        if let Some(rate_limiter) = ctx.accounts.peer.inbound_rate_limiter.as_mut() {
-            rate_limiter.try_consume(amount_received_ld)?;
-        }
-        // Refill the outbound rate limiter
-        if let Some(rate_limiter) = ctx.accounts.peer.outbound_rate_limiter.as_mut() {
-            rate_limiter.refill(amount_received_ld)?;
-        }
+        apply_inbound_rate_limits(
+            &mut ctx.accounts.peer,
+            &mut ctx.accounts.oft_store,
+            amount_received_ld,
+        )?;
 
+        // Tokens for a blocked recipient are quarantined in escrow rather than failing the
+        // message, which would block the channel.
+        let quarantined_ld =
+            if ctx.accounts.oft_store.blocklist_enabled && is_blocked(&ctx.accounts.to_blocklist) {
+                amount_received_ld
+            } else {
+                0
+            };
+        ctx.accounts.oft_store.quarantined_ld = ctx
+            .accounts
+            .oft_store
+            .quarantined_ld
+            .checked_add(quarantined_ld)
+            .ok_or(OFTError::MathOverflow)?;
+
+        // The inbound fee is retained in escrow. Composed messages can't be charged, as the
+        // amount in the compose message is derived in lz_receive_types which can't read the
+        // peer, so they are rejected on peers with an inbound fee and left to recover_lz_receive.
+        let compose_msg = msg_codec::compose_msg(&params.message, ctx.accounts.peer.svm_peer);
+        let inbound_fee_ld = match ctx.accounts.peer.inbound_fee_bps {
+            Some(inbound_fee_bps) if quarantined_ld == 0 => {
+                require!(compose_msg.is_none(), OFTError::ComposeWithInboundFee);
+                calculate_bps_fee(amount_received_ld, inbound_fee_bps)
+            },
+            _ => 0,
+        };
+
+        ctx.accounts.oft_store.add_fee(inbound_fee_ld)?;
+        let mut recovered_ld = 0;
         if ctx.accounts.oft_store.oft_type == OFTType::Adapter {
-            // unlock from escrow
-            ctx.accounts.oft_store.tvl_ld -= amount_received_ld;
+            // unlock from escrow, the inbound fee and quarantined tokens stay outside of the tvl
+            ctx.accounts.oft_store.tvl_ld = ctx
+                .accounts
+                .oft_store
+                .tvl_ld
+                .checked_sub(amount_received_ld)
+                .ok_or(OFTError::MathOverflow)?;
+            amount_received_ld -= inbound_fee_ld + quarantined_ld;
             token_interface::transfer_checked(
                 CpiContext::new(
                     ctx.accounts.token_program.to_account_info(),
@@ -127,32 +182,164 @@ impl LzReceive<'_> {
             // update the amount_received_ld with the post transfer fee amount
             amount_received_ld =
                 get_post_fee_amount_ld(&ctx.accounts.token_mint, amount_received_ld)?
-        } else if let Some(mint_authority) = &ctx.accounts.mint_authority {
-            // Native type
-            // mint
-            let ix = spl_token_2022::instruction::mint_to(
-                ctx.accounts.token_program.key,
-                &ctx.accounts.token_mint.key(),
-                &ctx.accounts.token_dest.key(),
-                mint_authority.key,
-                &[&ctx.accounts.oft_store.key()],
+        } else if ctx.accounts.oft_store.oft_type == OFTType::WrappedNative {
+            // unlock from escrow and unwrap, the inbound fee and quarantined tokens stay wrapped
+            ctx.accounts.oft_store.tvl_ld = ctx
+                .accounts
+                .oft_store
+                .tvl_ld
+                .checked_sub(amount_received_ld)
+                .ok_or(OFTError::MathOverflow)?;
+            amount_received_ld -= inbound_fee_ld + quarantined_ld;
+            // a token account can't be credited in lamports, and to_address can't be funded
+            // below its rent-exempt minimum. The amount then stays wrapped for a RecoveryClaim
+            if amount_received_ld > 0
+                && !ctx.accounts.can_unwrap_to_address(&params.message, amount_received_ld)?
+            {
+                recovered_ld = amount_received_ld;
+            }
+            ctx.accounts.unwrap_to_address(amount_received_ld - recovered_ld, seeds)?;
+        } else if ctx.accounts.oft_store.oft_type == OFTType::Hybrid {
+            // unlock from the tvl and mint the shortfall to escrow, then transfer from escrow.
+            // the inbound fee and quarantined tokens stay in escrow
+            let unlocked_ld = ctx.accounts.oft_store.unlock_up_to_tvl(amount_received_ld);
+            if amount_received_ld > unlocked_ld {
+                let mint_authority =
+                    ctx.accounts.mint_authority.as_ref().ok_or(OFTError::InvalidMintAuthority)?;
+                let ix = spl_token_2022::instruction::mint_to(
+                    ctx.accounts.token_program.key,
+                    &ctx.accounts.token_mint.key(),
+                    &ctx.accounts.token_escrow.key(),
+                    mint_authority.key,
+                    &[&ctx.accounts.oft_store.key()],
+                    amount_received_ld - unlocked_ld,
+                )?;
+                solana_program::program::invoke_signed(
+                    &ix,
+                    &[
+                        ctx.accounts.token_escrow.to_account_info(),
+                        ctx.accounts.token_mint.to_account_info(),
+                        mint_authority.to_account_info(),
+                        ctx.accounts.oft_store.to_account_info(),
+                    ],
+                    &[&seeds],
+                )?;
+            }
+            amount_received_ld -= inbound_fee_ld + quarantined_ld;
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.token_escrow.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: ctx.accounts.token_dest.to_account_info(),
+                        authority: ctx.accounts.oft_store.to_account_info(),
+                    },
+                )
+                .with_signer(&[&seeds]),
                 amount_received_ld,
+                ctx.accounts.token_mint.decimals,
             )?;
-            solana_program::program::invoke_signed(
-                &ix,
-                &[
-                    ctx.accounts.token_dest.to_account_info(),
-                    ctx.accounts.token_mint.to_account_info(),
-                    mint_authority.to_account_info(),
-                    ctx.accounts.oft_store.to_account_info(),
-                ],
-                &[&seeds],
-            )?;
+        } else if let Some(mint_authority) = &ctx.accounts.mint_authority {
+            // Native type
+            // mint, the inbound fee and quarantined tokens are minted to escrow
+            amount_received_ld -= inbound_fee_ld + quarantined_ld;
+            let mints = [
+                (ctx.accounts.token_dest.to_account_info(), amount_received_ld),
+                (ctx.accounts.token_escrow.to_account_info(), inbound_fee_ld + quarantined_ld),
+            ];
+            for (token_dest, amount_ld) in mints.into_iter().filter(|(_, amount)| *amount > 0) {
+                let ix = spl_token_2022::instruction::mint_to(
+                    ctx.accounts.token_program.key,
+                    &ctx.accounts.token_mint.key(),
+                    token_dest.key,
+                    mint_authority.key,
+                    &[&ctx.accounts.oft_store.key()],
+                    amount_ld,
+                )?;
+                solana_program::program::invoke_signed(
+                    &ix,
+                    &[
+                        token_dest,
+                        ctx.accounts.token_mint.to_account_info(),
+                        mint_authority.to_account_info(),
+                        ctx.accounts.oft_store.to_account_info(),
+                    ],
+                    &[&seeds],
+                )?;
+            }
         } else {
             return Err(OFTError::InvalidMintAuthority.into());
         }
 
-        if let Some(message) = msg_codec::compose_msg(&params.message) {
+        if quarantined_ld > 0 {
+            let oft_store = ctx.accounts.oft_store.key();
+            let bump = ctx.bumps.quarantine_record;
+            init_record(
+                &QuarantineRecord {
+                    oft_store,
+                    guid: params.guid,
+                    src_eid: params.src_eid,
+                    recipient: ctx.accounts.to_address.key(),
+                    amount_ld: quarantined_ld,
+                    created_at: Clock::get()?.unix_timestamp.try_into().unwrap(),
+                    rent_payer: ctx.accounts.payer.key(),
+                    bump,
+                },
+                &ctx.accounts.quarantine_record.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[QUARANTINE_SEED, oft_store.as_ref(), &params.guid, &[bump]],
+            )?;
+            emit_cpi!(OFTQuarantined {
+                guid: params.guid,
+                src_eid: params.src_eid,
+                to: ctx.accounts.to_address.key(),
+                amount_ld: quarantined_ld,
+            });
+            return Ok(());
+        }
+
+        if recovered_ld > 0 {
+            ctx.accounts.oft_store.claimable_ld = ctx
+                .accounts
+                .oft_store
+                .claimable_ld
+                .checked_add(recovered_ld)
+                .ok_or(OFTError::MathOverflow)?;
+            let recovery_claim = ctx
+                .accounts
+                .recovery_claim
+                .as_ref()
+                .ok_or(OFTError::WrappedNativeAccountsRequired)?;
+            let oft_store = ctx.accounts.oft_store.key();
+            let bump = ctx.bumps.recovery_claim;
+            init_record(
+                &RecoveryClaim {
+                    oft_store,
+                    guid: params.guid,
+                    src_eid: params.src_eid,
+                    recipient: ctx.accounts.to_address.key(),
+                    amount_ld: recovered_ld,
+                    created_at: Clock::get()?.unix_timestamp.try_into().unwrap(),
+                    rent_payer: ctx.accounts.payer.key(),
+                    bump,
+                },
+                &recovery_claim.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[RECOVERY_CLAIM_SEED, oft_store.as_ref(), &params.guid, &[bump]],
+            )?;
+            emit_cpi!(OFTRecovered {
+                guid: params.guid,
+                src_eid: params.src_eid,
+                to: ctx.accounts.to_address.key(),
+                amount_ld: recovered_ld,
+            });
+            return Ok(());
+        }
+
+        if let Some(message) = compose_msg {
             oapp::endpoint_cpi::send_compose(
                 ctx.accounts.oft_store.endpoint_program,
                 ctx.accounts.oft_store.key(),
@@ -177,7 +364,159 @@ impl LzReceive<'_> {
             src_eid: params.src_eid,
             to: ctx.accounts.to_address.key(),
             amount_received_ld,
+            inbound_fee_ld,
         });
         Ok(())
     }
+
+    fn can_unwrap_to_address(&self, message: &[u8], amount_ld: u64) -> Result<bool> {
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(self.to_address.data_len());
+        // the runtime rejects lamports credited to an executable account
+        Ok(msg_codec::to_token_account(message, self.peer.svm_peer).is_none()
+            && !self.to_address.executable
+            && self.to_address.lamports().saturating_add(amount_ld) >= rent_exempt_lamports)
+    }
+
+    /// Moves amount_ld of wSOL from token_escrow to the unwrap_account and closes it into the
+    /// oft_store, which pays the lamports out to to_address and refunds the rent to the payer.
+    fn unwrap_to_address(&self, amount_ld: u64, seeds: &[&[u8]]) -> Result<()> {
+        let unwrap_account =
+            self.unwrap_account.as_ref().ok_or(OFTError::WrappedNativeAccountsRequired)?;
+        require!(self.to_address.is_writable, OFTError::WrappedNativeAccountsRequired);
+        if amount_ld > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.token_escrow.to_account_info(),
+                        mint: self.token_mint.to_account_info(),
+                        to: unwrap_account.to_account_info(),
+                        authority: self.oft_store.to_account_info(),
+                    },
+                )
+                .with_signer(&[seeds]),
+                amount_ld,
+                self.token_mint.decimals,
+            )?;
+        }
+        // the amount and the rent of the unwrap_account
+        let unwrap_lamports = unwrap_account.to_account_info().lamports();
+        token_interface::close_account(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                CloseAccount {
+                    account: unwrap_account.to_account_info(),
+                    destination: self.oft_store.to_account_info(),
+                    authority: self.oft_store.to_account_info(),
+                },
+            )
+            .with_signer(&[seeds]),
+        )?;
+        **self.oft_store.to_account_info().try_borrow_mut_lamports()? -= unwrap_lamports;
+        **self.to_address.try_borrow_mut_lamports()? += amount_ld;
+        **self.payer.try_borrow_mut_lamports()? += unwrap_lamports - amount_ld;
+        Ok(())
+    }
+
+    fn validate_token_dest(&self, message: &[u8]) -> Result<()> {
+        if let Some(token_account) = msg_codec::to_token_account(message, self.peer.svm_peer) {
+            // integrators such as vault programs can have tokens land in any token account of
+            // the mint. It must be owned by to_address, which the blocklist is checked for
+            require!(
+                self.token_dest.key() == Pubkey::from(token_account),
+                OFTError::InvalidTokenDest
+            );
+            require!(self.token_dest.owner == self.token_program.key, OFTError::InvalidTokenDest);
+            let token_dest =
+                TokenAccount::try_deserialize(&mut &self.token_dest.try_borrow_data()?[..])?;
+            require!(token_dest.mint == self.token_mint.key(), OFTError::InvalidTokenDest);
+            require!(token_dest.owner == self.to_address.key(), OFTError::InvalidTokenDest);
+        } else {
+            require!(
+                self.token_dest.key()
+                    == get_associated_token_address_with_program_id(
+                        self.to_address.key,
+                        &self.token_mint.key(),
+                        self.token_program.key,
+                    ),
+                OFTError::InvalidTokenDest
+            );
+            associated_token::create_idempotent(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: self.payer.to_account_info(),
+                    associated_token: self.token_dest.to_account_info(),
+                    authority: self.to_address.to_account_info(),
+                    mint: self.token_mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+/// Shared by lz_receive and recover_lz_receive.
+pub(crate) fn apply_inbound_rate_limits(
+    peer: &mut PeerConfig,
+    oft_store: &mut OFTStore,
+    amount_received_ld: u64,
+) -> Result<()> {
+    // Consume the inbound rate limiter
+    if let Some(rate_limiter) = peer.inbound_rate_limiter.as_mut() {
+        rate_limiter.try_consume(amount_received_ld)?;
+    }
+    // Refill the outbound rate limiter
+    if let Some(rate_limiter) = peer.outbound_rate_limiter.as_mut() {
+        rate_limiter.refill(amount_received_ld)?;
+    }
+    // Same for the aggregate rate limiters of the oft_store
+    if let Some(rate_limiter) = oft_store.inbound_rate_limiter.as_mut() {
+        rate_limiter.try_consume(amount_received_ld)?;
+    }
+    if let Some(rate_limiter) = oft_store.outbound_rate_limiter.as_mut() {
+        rate_limiter.refill(amount_received_ld)?;
+    }
+    Ok(())
+}
+
+/// Creates a record account at its PDA the way anchor's init does, so that lamports sent to the
+/// PDA beforehand can't make the creation, and with it lz_receive, fail.
+/// Shared by lz_receive and recover_lz_receive.
+pub(crate) fn init_record<'info, T: AccountSerialize + Space>(
+    record: &T,
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let space = 8 + T::INIT_SPACE;
+    let rent_lamports = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if rent_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            rent_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+        )
+        .with_signer(&[seeds]),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+        )
+        .with_signer(&[seeds]),
+        &ID,
+    )?;
+    record.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
 }
@@ -24,14 +24,19 @@ pub struct LzReceiveTypes<'info> {
 // account 2 - oft store
 // account 3 - token escrow
 // account 4 - to address / wallet address
-// account 5 - token dest
+// account 5 - token dest, the token account of the message if it carries one, or the to address
+//             for WrappedNative
 // account 6 - token mint
 // account 7 - mint authority (optional)
 // account 8 - token program
 // account 9 - associated token program
 // account 10 - system program
-// account 11 - event authority
-// account 12 - this program
+// account 11 - blocklist pda of the to address
+// account 12 - unwrap account (optional, WrappedNative only)
+// account 13 - quarantine record pda of the message
+// account 14 - recovery claim pda of the message (optional, WrappedNative only)
+// account 15 - event authority
+// account 16 - this program
 // account remaining accounts
 //      0..9 - accounts for clear
 //      9..16 - accounts for compose
@@ -60,11 +65,21 @@ impl LzReceiveTypes<'_> {
         // account 4..9
         let to_address = Pubkey::from(msg_codec::send_to(&params.message));
         let token_program = ctx.accounts.token_mint.to_account_info().owner;
-        let token_dest = get_associated_token_address_with_program_id(
-            &to_address,
-            &ctx.accounts.oft_store.token_mint,
-            token_program,
-        );
+        let wrapped_native = ctx.accounts.oft_store.oft_type == OFTType::WrappedNative;
+        // The peer can't be read here, so a message that would carry a token account from an SVM
+        // peer is assumed to. lz_receive only credits it for SVM peers, such a message from any
+        // other peer must be executed with its associated token account or recovered.
+        let token_dest = if wrapped_native {
+            to_address
+        } else if let Some(token_account) = msg_codec::to_token_account(&params.message, true) {
+            Pubkey::from(token_account)
+        } else {
+            get_associated_token_address_with_program_id(
+                &to_address,
+                &ctx.accounts.oft_store.token_mint,
+                token_program,
+            )
+        };
         let mint_authority =
             if let COption::Some(mint_authority) = ctx.accounts.token_mint.mint_authority {
                 mint_authority
@@ -72,19 +87,40 @@ impl LzReceiveTypes<'_> {
                 ctx.program_id.key()
             };
         accounts.extend_from_slice(&[
-            LzAccount { pubkey: to_address, is_signer: false, is_writable: false }, // 4
-            LzAccount { pubkey: token_dest, is_signer: false, is_writable: true },  // 5
+            LzAccount { pubkey: to_address, is_signer: false, is_writable: wrapped_native }, // 4
+            LzAccount { pubkey: token_dest, is_signer: false, is_writable: true },           // 5
             LzAccount {
                 pubkey: ctx.accounts.token_mint.key(),
                 is_signer: false,
                 is_writable: true,
             }, // 6
-            LzAccount { pubkey: mint_authority, is_signer: false, is_writable: false }, // 7
-            LzAccount { pubkey: *token_program, is_signer: false, is_writable: false }, // 8
+            LzAccount { pubkey: mint_authority, is_signer: false, is_writable: false },      // 7
+            LzAccount { pubkey: *token_program, is_signer: false, is_writable: false },      // 8
             LzAccount { pubkey: ASSOCIATED_TOKEN_ID, is_signer: false, is_writable: false }, // 9
         ]);
 
-        // account 10..12
+        // account 10..16
+        let (to_blocklist, _) = Pubkey::find_program_address(
+            &[BLOCKLIST_SEED, ctx.accounts.oft_store.key().as_ref(), to_address.as_ref()],
+            ctx.program_id,
+        );
+        let (unwrap_account, recovery_claim) = if wrapped_native {
+            let (unwrap_account, _) = Pubkey::find_program_address(
+                &[UNWRAP_SEED, ctx.accounts.oft_store.key().as_ref()],
+                ctx.program_id,
+            );
+            let (recovery_claim, _) = Pubkey::find_program_address(
+                &[RECOVERY_CLAIM_SEED, ctx.accounts.oft_store.key().as_ref(), &params.guid],
+                ctx.program_id,
+            );
+            (unwrap_account, recovery_claim)
+        } else {
+            (ctx.program_id.key(), ctx.program_id.key())
+        };
+        let (quarantine_record, _) = Pubkey::find_program_address(
+            &[QUARANTINE_SEED, ctx.accounts.oft_store.key().as_ref(), &params.guid],
+            ctx.program_id,
+        );
         let (event_authority_account, _) =
             Pubkey::find_program_address(&[oapp::endpoint_cpi::EVENT_SEED], &ctx.program_id);
         accounts.extend_from_slice(&[
@@ -93,8 +129,12 @@ impl LzReceiveTypes<'_> {
                 is_signer: false,
                 is_writable: false,
             }, // 10
-            LzAccount { pubkey: event_authority_account, is_signer: false, is_writable: false }, // 11
-            LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }, // 12
+            LzAccount { pubkey: to_blocklist, is_signer: false, is_writable: false }, // 11
+            LzAccount { pubkey: unwrap_account, is_signer: false, is_writable: wrapped_native }, // 12
+            LzAccount { pubkey: quarantine_record, is_signer: false, is_writable: true }, // 13
+            LzAccount { pubkey: recovery_claim, is_signer: false, is_writable: wrapped_native }, // 14
+            LzAccount { pubkey: event_authority_account, is_signer: false, is_writable: false }, // 15
+            LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }, // 16
         ]);
 
         let endpoint_program = ctx.accounts.oft_store.endpoint_program;
@@ -109,7 +149,7 @@ impl LzReceiveTypes<'_> {
         accounts.extend(accounts_for_clear);
 
         // remaining accounts 9..16
-        if let Some(message) = msg_codec::compose_msg(&params.message) {
+        if let Some(message) = msg_codec::compose_msg(&params.message, true) {
             let amount_sd = msg_codec::amount_sd(&params.message);
             let amount_ld = ctx.accounts.oft_store.sd2ld(amount_sd);
             let amount_received_ld = if ctx.accounts.oft_store.oft_type == OFTType::Native {
@@ -0,0 +1,34 @@
+use crate::*;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Migrates an oft_store of the initial release to the current layout. The tokens held in
+/// token_escrow beyond tvl_ld are fees collected before the migration, they are accounted as
+/// accrued fees so that they aren't swept as surplus.
+#[derive(Accounts)]
+pub struct MigrateOFTStore<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: an OFTStore with the layout of the initial release, validated in migrate_account
+    #[account(mut, owner = crate::ID, seeds = [OFT_SEED, token_escrow.key().as_ref()], bump)]
+    pub oft_store: UncheckedAccount<'info>,
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateOFTStore<'_> {
+    pub fn apply(ctx: &mut Context<MigrateOFTStore>) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let escrow_amount = ctx.accounts.token_escrow.amount;
+        migrate_account::<OFTStore, OFTStoreV0>(
+            &ctx.accounts.oft_store,
+            &ctx.accounts.admin,
+            &ctx.accounts.system_program,
+            |legacy| {
+                require!(legacy.admin == admin, OFTError::Unauthorized);
+                let mut oft_store = OFTStore::from(legacy);
+                oft_store.accrued_fee_ld = escrow_amount.saturating_sub(oft_store.tvl_ld);
+                Ok(oft_store)
+            },
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Migrates a peer of the initial release to the current layout. The oft_store must have been
+/// migrated first.
+#[derive(Accounts)]
+#[instruction(params: MigratePeerConfigParams)]
+pub struct MigratePeerConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    /// CHECK: a PeerConfig with the layout of the initial release, validated in migrate_account
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [PEER_SEED, oft_store.key().as_ref(), &params.remote_eid.to_be_bytes()],
+        bump
+    )]
+    pub peer: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigratePeerConfig<'_> {
+    pub fn apply(
+        ctx: &mut Context<MigratePeerConfig>,
+        _params: &MigratePeerConfigParams,
+    ) -> Result<()> {
+        migrate_account::<PeerConfig, PeerConfigV0>(
+            &ctx.accounts.peer,
+            &ctx.accounts.admin,
+            &ctx.accounts.system_program,
+            |legacy| Ok(legacy.into()),
+        )
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MigratePeerConfigParams {
+    pub remote_eid: u32,
+}
@@ -1,21 +1,63 @@
+pub mod accept_admin;
+pub mod add_fee_exemption;
+pub mod approve_proposal;
+pub mod block_address;
+pub mod cancel_proposal;
+pub mod claim_recovered;
+pub mod claim_referral_rewards;
+pub mod create_proposal;
+pub mod distribute_fees;
+pub mod grant_role;
 pub mod init_oft;
+pub mod launch_token;
 pub mod lz_receive;
 pub mod lz_receive_types;
+pub mod migrate_oft_store;
+pub mod migrate_peer_config;
 pub mod quote_oft;
 pub mod quote_send;
+pub mod recover_lz_receive;
+pub mod register_referrer;
+pub mod release_quarantine;
+pub mod remove_fee_exemption;
+pub mod revoke_role;
 pub mod send;
 pub mod set_oft_config;
 pub mod set_pause;
 pub mod set_peer_config;
+pub mod sweep_surplus;
+pub mod unblock_address;
 pub mod withdraw_fee;
+pub mod withdraw_lamport_fee;
 
+pub use accept_admin::*;
+pub use add_fee_exemption::*;
+pub use approve_proposal::*;
+pub use block_address::*;
+pub use cancel_proposal::*;
+pub use claim_recovered::*;
+pub use claim_referral_rewards::*;
+pub use create_proposal::*;
+pub use distribute_fees::*;
+pub use grant_role::*;
 pub use init_oft::*;
+pub use launch_token::*;
 pub use lz_receive::*;
 pub use lz_receive_types::*;
+pub use migrate_oft_store::*;
+pub use migrate_peer_config::*;
 pub use quote_oft::*;
 pub use quote_send::*;
+pub use recover_lz_receive::*;
+pub use register_referrer::*;
+pub use release_quarantine::*;
+pub use remove_fee_exemption::*;
+pub use revoke_role::*;
 pub use send::*;
 pub use set_oft_config::*;
 pub use set_pause::*;
 pub use set_peer_config::*;
+pub use sweep_surplus::*;
+pub use unblock_address::*;
 pub use withdraw_fee::*;
+pub use withdraw_lamport_fee::*;
@@ -1,5 +1,9 @@
 use crate::*;
 use anchor_spl::token_interface::Mint;
+use fee_collector::{
+    state::{FeeAction, FeeSchedule as LaunchpadFeeSchedule},
+    FEE_SCHEDULE_SEED,
+};
 
 #[derive(Accounts)]
 #[instruction(params: QuoteOFTParams)]
@@ -20,6 +24,25 @@ pub struct QuoteOFT<'info> {
     pub peer: Account<'info, PeerConfig>,
     #[account(address = oft_store.token_mint)]
     pub token_mint: InterfaceAccount<'info, Mint>,
+    // Only required if params.sender is exempt from the oft fee
+    #[account(
+        constraint = params.sender == Some(fee_exemption.wallet) @OFTError::InvalidFeeExemption,
+        constraint = fee_exemption.applies_to(oft_store.key(), params.dst_eid)
+            @OFTError::InvalidFeeExemption
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+    // Only required if the oft_store has a launchpad fee
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED, fee_collector_schedule.fee_config.as_ref()],
+        bump = fee_collector_schedule.bump,
+        seeds::program = fee_collector::ID,
+        constraint = oft_store.launchpad_fee.as_ref().is_some_and(|launchpad_fee| {
+            launchpad_fee.fee_config == fee_collector_schedule.fee_config
+        }) @OFTError::LaunchpadFeeAccountsRequired
+    )]
+    pub fee_collector_schedule: Option<Account<'info, LaunchpadFeeSchedule>>,
+    // Only required to include the sender rate limit in oft_limits, requires params.sender
+    pub sender_rate_limiter: Option<Account<'info, SenderRateLimiter>>,
 }
 
 impl QuoteOFT<'_> {
@@ -30,11 +53,59 @@ impl QuoteOFT<'_> {
             params.amount_ld,
             &ctx.accounts.oft_store,
             &ctx.accounts.token_mint,
-            ctx.accounts.peer.fee_bps,
+            &ctx.accounts.peer,
+            ctx.accounts.fee_exemption.is_some(),
         )?;
         require!(amount_received_ld >= params.min_amount_ld, OFTError::SlippageExceeded);
 
-        let oft_limits = OFTLimits { min_amount_ld: 0, max_amount_ld: 0xffffffffffffffff };
+        // max_send_ld and the outbound rate limiters of the peer, the oft_store and the sender
+        // all apply. The rate limiters consume amount_received_ld, so max_send_ld is converted
+        // to the amount received for it
+        let max_send_received_ld = match ctx.accounts.peer.max_send_ld {
+            Some(max_send_ld) => {
+                compute_fee_and_adjust_amount(
+                    max_send_ld,
+                    &ctx.accounts.oft_store,
+                    &ctx.accounts.token_mint,
+                    &ctx.accounts.peer,
+                    ctx.accounts.fee_exemption.is_some(),
+                )?
+                .1
+            },
+            None => u64::MAX,
+        };
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        let sender_available_ld =
+            match (&ctx.accounts.peer.sender_rate_limit, &ctx.accounts.sender_rate_limiter) {
+                (Some(sender_rate_limit), Some(sender_rate_limiter)) => {
+                    let sender = params.sender.ok_or(OFTError::InvalidRateLimiter)?;
+                    let (expected, _) = Pubkey::find_program_address(
+                        &[
+                            SENDER_RATE_LIMITER_SEED,
+                            ctx.accounts.oft_store.key().as_ref(),
+                            sender.as_ref(),
+                            &params.dst_eid.to_be_bytes(),
+                        ],
+                        ctx.program_id,
+                    );
+                    require!(sender_rate_limiter.key() == expected, OFTError::InvalidRateLimiter);
+                    sender_rate_limiter.available_at(sender_rate_limit, current_time)
+                },
+                _ => u64::MAX,
+            };
+        let max_amount_ld = [
+            &ctx.accounts.peer.outbound_rate_limiter,
+            &ctx.accounts.oft_store.outbound_rate_limiter,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|rate_limiter| rate_limiter.available_at(current_time))
+        .chain([sender_available_ld])
+        .fold(max_send_received_ld, std::cmp::min);
+        let oft_limits = OFTLimits {
+            min_amount_ld: ctx.accounts.peer.min_send_ld.unwrap_or_default(),
+            max_amount_ld,
+        };
         let mut oft_fee_details = if amount_received_ld + oft_fee_ld < amount_sent_ld {
             vec![OFTFeeDetail {
                 fee_amount_ld: amount_sent_ld - oft_fee_ld - amount_received_ld,
@@ -43,15 +114,34 @@ impl QuoteOFT<'_> {
         } else {
             vec![]
         };
-        // cross chain fee
-        if oft_fee_ld > 0 {
+        // cross chain fee, reported as zero for exempt senders
+        if oft_fee_ld > 0 || ctx.accounts.fee_exemption.is_some() {
             oft_fee_details.push(OFTFeeDetail {
                 fee_amount_ld: oft_fee_ld,
                 description: "Cross Chain Fee".to_string(),
             });
         }
         let oft_receipt = OFTReceipt { amount_sent_ld, amount_received_ld };
-        Ok(QuoteOFTResult { oft_limits, oft_fee_details, oft_receipt })
+        // send fails the same way when the launchpad fee can't be collected
+        let launchpad_fee = match ctx.accounts.oft_store.launchpad_fee {
+            Some(_) => ctx
+                .accounts
+                .fee_collector_schedule
+                .as_ref()
+                .ok_or(OFTError::LaunchpadFeeAccountsRequired)?
+                .price_of(FeeAction::Bridge)
+                .ok_or(OFTError::LaunchpadFeeNotPriced)?,
+            None => 0,
+        };
+        let lamport_fee = calculate_lamport_fee(
+            amount_received_ld,
+            &ctx.accounts.oft_store,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.peer,
+            ctx.accounts.fee_exemption.is_some(),
+        );
+        let lamport_fee = lamport_fee.saturating_add(launchpad_fee);
+        Ok(QuoteOFTResult { oft_limits, oft_fee_details, oft_receipt, lamport_fee })
     }
 }
 
@@ -64,6 +154,7 @@ pub struct QuoteOFTParams {
     pub options: Vec<u8>,
     pub compose_msg: Option<Vec<u8>>,
     pub pay_in_lz_token: bool,
+    pub sender: Option<Pubkey>, // owner of the sender_rate_limiter and fee_exemption, if passed
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
@@ -71,6 +162,7 @@ pub struct QuoteOFTResult {
     pub oft_limits: OFTLimits,
     pub oft_fee_details: Vec<OFTFeeDetail>,
     pub oft_receipt: OFTReceipt,
+    pub lamport_fee: u64, // oft and launchpad fees paid in lamports, on top of the messaging fee
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
@@ -88,5 +180,6 @@ pub struct OFTReceipt {
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct OFTLimits {
     pub min_amount_ld: u64,
+    // in amount_received_ld, which is at most the amount sent, so sending it is always accepted
     pub max_amount_ld: u64,
 }
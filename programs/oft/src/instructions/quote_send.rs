@@ -31,17 +31,33 @@ pub struct QuoteSend<'info> {
     pub peer: Account<'info, PeerConfig>,
     #[account(address = oft_store.token_mint)]
     pub token_mint: InterfaceAccount<'info, Mint>,
+    // Only required if params.sender is exempt from the oft fee
+    #[account(
+        constraint = params.sender == Some(fee_exemption.wallet) @OFTError::InvalidFeeExemption,
+        constraint = fee_exemption.applies_to(oft_store.key(), params.dst_eid)
+            @OFTError::InvalidFeeExemption
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 }
 
 impl QuoteSend<'_> {
     pub fn apply(ctx: &Context<QuoteSend>, params: &QuoteSendParams) -> Result<MessagingFee> {
         require!(!ctx.accounts.oft_store.paused, OFTError::Paused);
+        require!(
+            params.to_token_account.is_none() || params.compose_msg.is_none(),
+            OFTError::ComposeToTokenAccount
+        );
+        require!(
+            params.to_token_account.is_none() || ctx.accounts.peer.svm_peer,
+            OFTError::ToTokenAccountNotSupported
+        );
 
         let (_, amount_received_ld, _) = compute_fee_and_adjust_amount(
             params.amount_ld,
             &ctx.accounts.oft_store,
             &ctx.accounts.token_mint,
-            ctx.accounts.peer.fee_bps,
+            &ctx.accounts.peer,
+            ctx.accounts.fee_exemption.is_some(),
         )?;
         require!(amount_received_ld >= params.min_amount_ld, OFTError::SlippageExceeded);
 
@@ -58,6 +74,7 @@ impl QuoteSend<'_> {
                     amount_received_ld,
                     Pubkey::default(),
                     &params.compose_msg,
+                    params.to_token_account,
                 ),
                 pay_in_lz_token: params.pay_in_lz_token,
                 options: ctx
@@ -74,7 +91,8 @@ pub fn compute_fee_and_adjust_amount(
     amount_ld: u64,
     oft_store: &OFTStore,
     token_mint: &InterfaceAccount<Mint>,
-    fee_bps: Option<u16>,
+    peer: &PeerConfig,
+    fee_exempt: bool,
 ) -> Result<(u64, u64, u64)> {
     let (amount_sent_ld, amount_received_ld, oft_fee_ld) = if OFTType::Adapter == oft_store.oft_type
     {
@@ -83,35 +101,58 @@ pub fn compute_fee_and_adjust_amount(
         let amount_sent_ld = get_pre_fee_amount_ld(token_mint, amount_received_ld)?;
 
         // remove the oft fee from the amount_received_ld
-        let oft_fee_ld = oft_store.remove_dust(calculate_fee(
-            amount_received_ld,
-            oft_store.default_fee_bps,
-            fee_bps,
-        ));
+        let oft_fee_ld =
+            oft_store.remove_dust(calculate_fee(amount_received_ld, oft_store, peer, fee_exempt));
         amount_received_ld -= oft_fee_ld;
         (amount_sent_ld, amount_received_ld, oft_fee_ld)
     } else {
-        // if it is Native OFT, there is no transfer fee
+        // if it is Native, WrappedNative or Hybrid OFT, there is no transfer fee
         let amount_sent_ld = oft_store.remove_dust(amount_ld);
-        let oft_fee_ld = oft_store.remove_dust(calculate_fee(
-            amount_sent_ld,
-            oft_store.default_fee_bps,
-            fee_bps,
-        ));
+        let oft_fee_ld =
+            oft_store.remove_dust(calculate_fee(amount_sent_ld, oft_store, peer, fee_exempt));
         let amount_received_ld = amount_sent_ld - oft_fee_ld;
         (amount_sent_ld, amount_received_ld, oft_fee_ld)
     };
     Ok((amount_sent_ld, amount_received_ld, oft_fee_ld))
 }
 
-fn calculate_fee(pre_fee_amount: u64, default_fee_bps: u16, fee_bps: Option<u16>) -> u64 {
-    let final_fee_bps = if let Some(bps) = fee_bps { bps as u128 } else { default_fee_bps as u128 };
-    if final_fee_bps == 0 || pre_fee_amount == 0 {
+/// The oft fee in lamports, if the oft_store charges the oft fee in lamports instead of tokens.
+pub fn calculate_lamport_fee(
+    amount_received_ld: u64,
+    oft_store: &OFTStore,
+    token_mint: &InterfaceAccount<Mint>,
+    peer: &PeerConfig,
+    fee_exempt: bool,
+) -> u64 {
+    match &oft_store.lamport_fee_config {
+        Some(lamport_fee_config) if !fee_exempt => lamport_fee_config
+            .calculate_fee(resolve_fee(amount_received_ld, oft_store, peer), token_mint.decimals),
+        _ => 0,
+    }
+}
+
+fn calculate_fee(
+    pre_fee_amount: u64,
+    oft_store: &OFTStore,
+    peer: &PeerConfig,
+    fee_exempt: bool,
+) -> u64 {
+    if fee_exempt || oft_store.lamport_fee_config.is_some() {
         0
     } else {
-        // pre_fee_amount * final_fee_bps / ONE_IN_BASIS_POINTS
-        let fee = (pre_fee_amount as u128) * final_fee_bps;
-        (fee / ONE_IN_BASIS_POINTS) as u64
+        resolve_fee(pre_fee_amount, oft_store, peer)
+    }
+}
+
+fn resolve_fee(pre_fee_amount: u64, oft_store: &OFTStore, peer: &PeerConfig) -> u64 {
+    if let Some(fee_schedule) = &peer.fee_schedule {
+        fee_schedule.calculate_fee(pre_fee_amount)
+    } else if let Some(fee_bps) = peer.fee_bps {
+        calculate_bps_fee(pre_fee_amount, fee_bps)
+    } else if let Some(fee_schedule) = &oft_store.default_fee_schedule {
+        fee_schedule.calculate_fee(pre_fee_amount)
+    } else {
+        calculate_bps_fee(pre_fee_amount, oft_store.default_fee_bps)
     }
 }
 
@@ -131,6 +172,13 @@ pub fn get_post_fee_amount_ld(token_mint: &InterfaceAccount<Mint>, amount_ld: u6
     Ok(post_amount_ld)
 }
 
+pub fn has_transfer_fee(token_mint: &InterfaceAccount<Mint>) -> Result<bool> {
+    let token_mint_info = token_mint.to_account_info();
+    let token_mint_data = token_mint_info.try_borrow_data()?;
+    let token_mint_ext = StateWithExtensions::<MintState>::unpack(&token_mint_data)?;
+    Ok(token_mint_ext.get_extension::<TransferFeeConfig>().is_ok())
+}
+
 // Calculate the amount_sent_ld necessary to receive amount_received_ld
 // Does *not* de-dust any inputs or outputs.
 fn get_pre_fee_amount_ld(token_mint: &InterfaceAccount<Mint>, amount_ld: u64) -> Result<u64> {
@@ -190,4 +238,6 @@ pub struct QuoteSendParams {
     pub options: Vec<u8>,
     pub compose_msg: Option<Vec<u8>>,
     pub pay_in_lz_token: bool,
+    pub to_token_account: Option<[u8; 32]>,
+    pub sender: Option<Pubkey>, // the wallet of the fee_exemption account, if passed
 }
@@ -0,0 +1,218 @@
+use crate::*;
+use anchor_lang::solana_program;
+use anchor_spl::{
+    token_2022::spl_token_2022::{self, solana_program::program_option::COption},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use oapp::endpoint::{cpi::accounts::Clear, instructions::ClearParams, ConstructCPIContext};
+
+/// Fallback for inbound messages that lz_receive can't deliver, e.g. because the recipient
+/// can't hold an associated token account. Clears the message and credits the amount to a
+/// RecoveryClaim instead, so that one bad message doesn't block the pathway.
+/// The inbound fee of the peer and the blocklist apply as in lz_receive, the amount for a
+/// blocked recipient goes to a QuarantineRecord instead. Compose messages of recovered
+/// messages are dropped.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: LzReceiveParams)]
+pub struct RecoverLzReceive<'info> {
+    /// admin or a recoverer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        seeds = [
+            PEER_SEED,
+            oft_store.key().as_ref(),
+            &params.src_eid.to_be_bytes()
+        ],
+        bump = peer.bump,
+        constraint = peer.peer_address == params.sender @OFTError::InvalidSender
+    )]
+    pub peer: Account<'info, PeerConfig>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = is_admin_or_role_holder(
+            &oft_store,
+            signer.key(),
+            &role_assignment,
+            Some(Role::Recoverer)
+        ) @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    // Only used for native and hybrid mints, see lz_receive
+    #[account(constraint = token_mint.mint_authority == COption::Some(mint_authority.key()) @OFTError::InvalidMintAuthority)]
+    pub mint_authority: Option<AccountInfo<'info>>,
+    /// CHECK: the BlockedAddress PDA of the recipient
+    #[account(
+        seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), &msg_codec::send_to(&params.message)],
+        bump
+    )]
+    pub to_blocklist: UncheckedAccount<'info>,
+    // Required unless the recipient is blocked
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + RecoveryClaim::INIT_SPACE,
+        seeds = [RECOVERY_CLAIM_SEED, oft_store.key().as_ref(), &params.guid],
+        bump
+    )]
+    pub recovery_claim: Option<Account<'info, RecoveryClaim>>,
+    /// CHECK: the QuarantineRecord PDA of the message, only created if the recipient is blocked
+    #[account(mut, seeds = [QUARANTINE_SEED, oft_store.key().as_ref(), &params.guid], bump)]
+    pub quarantine_record: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RecoverLzReceive<'_> {
+    pub fn apply(ctx: &mut Context<RecoverLzReceive>, params: &LzReceiveParams) -> Result<()> {
+        require!(!ctx.accounts.oft_store.paused, OFTError::Paused);
+
+        let oft_store_seed = ctx.accounts.token_escrow.key();
+        let seeds: &[&[u8]] = &[OFT_SEED, oft_store_seed.as_ref(), &[ctx.accounts.oft_store.bump]];
+
+        // Validate and clear the payload
+        let accounts_for_clear = &ctx.remaining_accounts[0..Clear::MIN_ACCOUNTS_LEN];
+        let _ = oapp::endpoint_cpi::clear(
+            ctx.accounts.oft_store.endpoint_program,
+            ctx.accounts.oft_store.key(),
+            accounts_for_clear,
+            seeds,
+            ClearParams {
+                receiver: ctx.accounts.oft_store.key(),
+                src_eid: params.src_eid,
+                sender: params.sender,
+                nonce: params.nonce,
+                guid: params.guid,
+                message: params.message.clone(),
+            },
+        )?;
+
+        let amount_sd = msg_codec::amount_sd(&params.message);
+        let amount_ld = ctx.accounts.oft_store.sd2ld(amount_sd);
+        apply_inbound_rate_limits(&mut ctx.accounts.peer, &mut ctx.accounts.oft_store, amount_ld)?;
+
+        // Hold the amount in escrow, outside of the tvl. The inbound fee is retained as in
+        // lz_receive, and not charged on quarantined tokens
+        let blocked =
+            ctx.accounts.oft_store.blocklist_enabled && is_blocked(&ctx.accounts.to_blocklist);
+        let inbound_fee_ld = match ctx.accounts.peer.inbound_fee_bps {
+            Some(inbound_fee_bps) if !blocked => calculate_bps_fee(amount_ld, inbound_fee_bps),
+            _ => 0,
+        };
+        ctx.accounts.oft_store.add_fee(inbound_fee_ld)?;
+        let held_ld = amount_ld - inbound_fee_ld;
+        if blocked {
+            ctx.accounts.oft_store.quarantined_ld = ctx
+                .accounts
+                .oft_store
+                .quarantined_ld
+                .checked_add(held_ld)
+                .ok_or(OFTError::MathOverflow)?;
+        } else {
+            ctx.accounts.oft_store.claimable_ld = ctx
+                .accounts
+                .oft_store
+                .claimable_ld
+                .checked_add(held_ld)
+                .ok_or(OFTError::MathOverflow)?;
+        }
+        // Native and Hybrid mint what isn't unlocked from the tvl
+        let unlocked_ld = match ctx.accounts.oft_store.oft_type {
+            OFTType::Native => 0,
+            OFTType::Hybrid => amount_ld.min(ctx.accounts.oft_store.tvl_ld),
+            OFTType::Adapter | OFTType::WrappedNative => amount_ld,
+        };
+        ctx.accounts.oft_store.tvl_ld =
+            ctx.accounts.oft_store.tvl_ld.checked_sub(unlocked_ld).ok_or(OFTError::MathOverflow)?;
+        if unlocked_ld < amount_ld {
+            let mint_authority =
+                ctx.accounts.mint_authority.as_ref().ok_or(OFTError::InvalidMintAuthority)?;
+            let ix = spl_token_2022::instruction::mint_to(
+                ctx.accounts.token_program.key,
+                &ctx.accounts.token_mint.key(),
+                &ctx.accounts.token_escrow.key(),
+                mint_authority.key,
+                &[&ctx.accounts.oft_store.key()],
+                amount_ld - unlocked_ld,
+            )?;
+            solana_program::program::invoke_signed(
+                &ix,
+                &[
+                    ctx.accounts.token_escrow.to_account_info(),
+                    ctx.accounts.token_mint.to_account_info(),
+                    mint_authority.to_account_info(),
+                    ctx.accounts.oft_store.to_account_info(),
+                ],
+                &[&seeds],
+            )?;
+        }
+
+        let recipient = Pubkey::from(msg_codec::send_to(&params.message));
+        let created_at = Clock::get()?.unix_timestamp.try_into().unwrap();
+        if blocked {
+            let oft_store = ctx.accounts.oft_store.key();
+            let bump = ctx.bumps.quarantine_record;
+            init_record(
+                &QuarantineRecord {
+                    oft_store,
+                    guid: params.guid,
+                    src_eid: params.src_eid,
+                    recipient,
+                    amount_ld: held_ld,
+                    created_at,
+                    rent_payer: ctx.accounts.signer.key(),
+                    bump,
+                },
+                &ctx.accounts.quarantine_record.to_account_info(),
+                &ctx.accounts.signer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[QUARANTINE_SEED, oft_store.as_ref(), &params.guid, &[bump]],
+            )?;
+            emit_cpi!(OFTQuarantined {
+                guid: params.guid,
+                src_eid: params.src_eid,
+                to: recipient,
+                amount_ld: held_ld,
+            });
+            return Ok(());
+        }
+
+        let recovery_claim =
+            ctx.accounts.recovery_claim.as_mut().ok_or(OFTError::RecoveryClaimRequired)?;
+        recovery_claim.oft_store = ctx.accounts.oft_store.key();
+        recovery_claim.guid = params.guid;
+        recovery_claim.src_eid = params.src_eid;
+        recovery_claim.recipient = recipient;
+        recovery_claim.amount_ld = held_ld;
+        recovery_claim.created_at = created_at;
+        recovery_claim.rent_payer = ctx.accounts.signer.key();
+        recovery_claim.bump = ctx.bumps.recovery_claim;
+
+        emit_cpi!(OFTRecovered {
+            guid: params.guid,
+            src_eid: params.src_eid,
+            to: recipient,
+            amount_ld: held_ld,
+        });
+        Ok(())
+    }
+}
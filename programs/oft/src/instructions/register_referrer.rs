@@ -0,0 +1,31 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [REFERRER_SEED, oft_store.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RegisterReferrer<'_> {
+    pub fn apply(ctx: &mut Context<RegisterReferrer>) -> Result<()> {
+        ctx.accounts.referrer.oft_store = ctx.accounts.oft_store.key();
+        ctx.accounts.referrer.wallet = ctx.accounts.wallet.key();
+        ctx.accounts.referrer.accrued_ld = 0;
+        ctx.accounts.referrer.bump = ctx.bumps.referrer;
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+/// Releases the funds of a message that lz_receive or recover_lz_receive quarantined to its
+/// recipient, once the recipient is unblocked.
+#[derive(Accounts)]
+#[instruction(params: ReleaseQuarantineParams)]
+pub struct ReleaseQuarantine<'info> {
+    /// admin, or anyone executing a ready proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = proposer,
+        constraint = proposer.as_ref().is_some_and(|proposer| proposer.key() == proposal.proposer)
+            @OFTError::InvalidProposal
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+    /// CHECK: refunded the rent of the proposal. Only required with the proposal
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [QUARANTINE_SEED, oft_store.key().as_ref(), &params.guid],
+        bump = quarantine_record.bump
+    )]
+    pub quarantine_record: Account<'info, QuarantineRecord>,
+    /// CHECK: refunded the rent of the quarantine_record
+    #[account(mut, address = quarantine_record.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    /// the recipient if it's a token account, or a token account of the recipient
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+        constraint = token_dest.key() == quarantine_record.recipient
+            || token_dest.owner == quarantine_record.recipient @OFTError::InvalidTokenDest
+    )]
+    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: the BlockedAddress PDA of the owner of token_dest, which must not exist
+    #[account(seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), token_dest.owner.as_ref()], bump)]
+    pub to_blocklist: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ReleaseQuarantine<'_> {
+    pub fn apply(
+        ctx: &mut Context<ReleaseQuarantine>,
+        params: &ReleaseQuarantineParams,
+    ) -> Result<()> {
+        authorize_admin_action(
+            &ctx.accounts.oft_store,
+            ctx.accounts.signer.key(),
+            &ctx.accounts.role_assignment,
+            &ctx.accounts.proposal,
+            &AdminAction::ReleaseQuarantine {
+                token_dest: ctx.accounts.token_dest.key(),
+                params: params.clone(),
+            },
+        )?;
+        require!(!is_blocked(&ctx.accounts.to_blocklist), OFTError::AddressBlocked);
+        let amount_ld = ctx.accounts.quarantine_record.amount_ld;
+        ctx.accounts.oft_store.quarantined_ld = ctx
+            .accounts
+            .oft_store
+            .quarantined_ld
+            .checked_sub(amount_ld)
+            .ok_or(OFTError::MathOverflow)?;
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            &ctx.accounts.token_escrow.key().to_bytes(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_escrow.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.oft_store.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds]),
+            amount_ld,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        emit!(QuarantineReleased {
+            oft_store: ctx.accounts.oft_store.key(),
+            guid: params.guid,
+            token_dest: ctx.accounts.token_dest.key(),
+            amount_ld,
+        });
+        Ok(())
+    }
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ReleaseQuarantineParams {
+    pub guid: [u8; 32],
+}
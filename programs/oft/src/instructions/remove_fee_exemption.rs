@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemption<'info> {
+    /// admin or a fee manager
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = is_admin_or_role_holder(
+            &oft_store,
+            signer.key(),
+            &role_assignment,
+            Some(Role::FeeManager)
+        ) @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            FEE_EXEMPTION_SEED,
+            oft_store.key().as_ref(),
+            fee_exemption.wallet.as_ref(),
+            &fee_exemption.dst_eid.to_be_bytes()
+        ],
+        bump = fee_exemption.bump
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+}
+
+impl RemoveFeeExemption<'_> {
+    pub fn apply(ctx: &mut Context<RemoveFeeExemption>) -> Result<()> {
+        emit!(FeeExemptionRemoved {
+            oft_store: ctx.accounts.oft_store.key(),
+            wallet: ctx.accounts.fee_exemption.wallet,
+            dst_eid: ctx.accounts.fee_exemption.dst_eid,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            ROLE_SEED,
+            oft_store.key().as_ref(),
+            &[role_assignment.role as u8],
+            role_assignment.holder.as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+impl RevokeRole<'_> {
+    pub fn apply(ctx: &mut Context<RevokeRole>) -> Result<()> {
+        emit!(RoleRevoked {
+            oft_store: ctx.accounts.oft_store.key(),
+            role: ctx.accounts.role_assignment.role,
+            holder: ctx.accounts.role_assignment.holder,
+        });
+        Ok(())
+    }
+}
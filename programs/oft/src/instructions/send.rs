@@ -1,6 +1,11 @@
 use crate::*;
+use anchor_lang::system_program;
 use anchor_spl::token_interface::{
-    self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+    self, Burn, Mint, SyncNative, TokenAccount, TokenInterface, TransferChecked,
+};
+use fee_collector::{
+    program::FeeCollector,
+    state::{FeeAction, FeeConfig},
 };
 use oapp::endpoint::{instructions::SendParams as EndpointSendParams, MessagingReceipt};
 
@@ -8,6 +13,7 @@ use oapp::endpoint::{instructions::SendParams as EndpointSendParams, MessagingRe
 #[derive(Accounts)]
 #[instruction(params: SendParams)]
 pub struct Send<'info> {
+    #[account(mut)]
     pub signer: Signer<'info>,
     #[account(
         mut,
@@ -25,13 +31,14 @@ pub struct Send<'info> {
         bump = oft_store.bump
     )]
     pub oft_store: Account<'info, OFTStore>,
+    // Not required for WrappedNative, which wraps lamports of the signer
     #[account(
         mut,
         token::authority = signer,
         token::mint = token_mint,
         token::token_program = token_program
     )]
-    pub token_source: InterfaceAccount<'info, TokenAccount>,
+    pub token_source: Option<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         address = oft_store.token_escrow,
@@ -47,6 +54,67 @@ pub struct Send<'info> {
     )]
     pub token_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
+    // Only required if the peer has a sender_rate_limit
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + SenderRateLimiter::INIT_SPACE,
+        seeds = [
+            SENDER_RATE_LIMITER_SEED,
+            oft_store.key().as_ref(),
+            signer.key().as_ref(),
+            &params.dst_eid.to_be_bytes()
+        ],
+        bump
+    )]
+    pub sender_rate_limiter: Option<Account<'info, SenderRateLimiter>>,
+    // Only required if the signer is exempt from the oft fee
+    #[account(
+        constraint = fee_exemption.wallet == signer.key() @OFTError::InvalidFeeExemption,
+        constraint = fee_exemption.applies_to(oft_store.key(), params.dst_eid)
+            @OFTError::InvalidFeeExemption
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+    // Only required if params.referrer is set. The self-referral check is advisory only, as a
+    // sender can always refer itself from a second wallet
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED, oft_store.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+        constraint = params.referrer == Some(referrer.wallet) @OFTError::InvalidReferrer,
+        constraint = referrer.wallet != signer.key() @OFTError::InvalidReferrer
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+    // Only required if the oft_store charges the oft fee in lamports
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + LamportFeeVault::INIT_SPACE,
+        seeds = [LAMPORT_FEE_VAULT_SEED, oft_store.key().as_ref()],
+        bump
+    )]
+    pub lamport_fee_vault: Option<Account<'info, LamportFeeVault>>,
+    // Only required if the oft_store has a launchpad_fee
+    #[account(
+        constraint = oft_store.launchpad_fee.as_ref().is_some_and(|launchpad_fee| {
+            launchpad_fee.fee_config == fee_collector_config.key()
+        }) @OFTError::LaunchpadFeeAccountsRequired
+    )]
+    pub fee_collector_config: Option<Account<'info, FeeConfig>>,
+    /// CHECK: validated by the fee_collector program
+    pub fee_collector_schedule: Option<UncheckedAccount<'info>>,
+    /// CHECK: validated by the fee_collector program
+    #[account(mut)]
+    pub fee_collector_vault: Option<UncheckedAccount<'info>>,
+    pub fee_collector_program: Option<Program<'info, FeeCollector>>,
+    // Only required if the oft_store has the blocklist enabled
+    /// CHECK: the BlockedAddress PDA of the signer, which must not exist
+    #[account(seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), signer.key().as_ref()], bump)]
+    pub signer_blocklist: Option<UncheckedAccount<'info>>,
+    /// CHECK: the BlockedAddress PDA of the recipient, which must not exist
+    #[account(seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), &params.to], bump)]
+    pub to_blocklist: Option<UncheckedAccount<'info>>,
+    pub system_program: Option<Program<'info, System>>,
 }
 
 impl Send<'_> {
@@ -55,30 +123,145 @@ impl Send<'_> {
         params: &SendParams,
     ) -> Result<(MessagingReceipt, OFTReceipt)> {
         require!(!ctx.accounts.oft_store.paused, OFTError::Paused);
+        require!(
+            params.to_token_account.is_none() || params.compose_msg.is_none(),
+            OFTError::ComposeToTokenAccount
+        );
+        require!(
+            params.to_token_account.is_none() || ctx.accounts.peer.svm_peer,
+            OFTError::ToTokenAccountNotSupported
+        );
+        if ctx.accounts.oft_store.blocklist_enabled {
+            let (Some(signer_blocklist), Some(to_blocklist)) =
+                (&ctx.accounts.signer_blocklist, &ctx.accounts.to_blocklist)
+            else {
+                return Err(OFTError::BlocklistAccountsRequired.into());
+            };
+            require!(
+                !is_blocked(signer_blocklist) && !is_blocked(to_blocklist),
+                OFTError::AddressBlocked
+            );
+        }
 
         let (amount_sent_ld, amount_received_ld, oft_fee_ld) = compute_fee_and_adjust_amount(
             params.amount_ld,
             &ctx.accounts.oft_store,
             &ctx.accounts.token_mint,
-            ctx.accounts.peer.fee_bps,
+            &ctx.accounts.peer,
+            ctx.accounts.fee_exemption.is_some(),
         )?;
         require!(amount_received_ld >= params.min_amount_ld, OFTError::SlippageExceeded);
+        ctx.accounts.peer.check_send_amount(amount_sent_ld)?;
+
+        let lamport_fee = calculate_lamport_fee(
+            amount_received_ld,
+            &ctx.accounts.oft_store,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.peer,
+            ctx.accounts.fee_exemption.is_some(),
+        );
+        if ctx.accounts.oft_store.lamport_fee_config.is_some() {
+            let lamport_fee_vault =
+                ctx.accounts.lamport_fee_vault.as_mut().ok_or(OFTError::LamportFeeVaultRequired)?;
+            lamport_fee_vault.bump = ctx.bumps.lamport_fee_vault;
+            if lamport_fee > 0 {
+                let system_program = ctx
+                    .accounts
+                    .system_program
+                    .as_ref()
+                    .ok_or(OFTError::LamportFeeVaultRequired)?;
+                system_program::transfer(
+                    CpiContext::new(
+                        system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.signer.to_account_info(),
+                            to: lamport_fee_vault.to_account_info(),
+                        },
+                    ),
+                    lamport_fee,
+                )?;
+            }
+        }
 
         if let Some(rate_limiter) = ctx.accounts.peer.outbound_rate_limiter.as_mut() {
             rate_limiter.try_consume(amount_received_ld)?;
         }
+        if let Some(sender_rate_limit) = &ctx.accounts.peer.sender_rate_limit {
+            let sender_rate_limiter = ctx
+                .accounts
+                .sender_rate_limiter
+                .as_mut()
+                .ok_or(OFTError::SenderRateLimiterRequired)?;
+            sender_rate_limiter.bump = ctx.bumps.sender_rate_limiter;
+            sender_rate_limiter.try_consume(sender_rate_limit, amount_received_ld)?;
+        }
         if let Some(rate_limiter) = ctx.accounts.peer.inbound_rate_limiter.as_mut() {
             rate_limiter.refill(amount_received_ld)?;
         }
+        if let Some(rate_limiter) = ctx.accounts.oft_store.outbound_rate_limiter.as_mut() {
+            rate_limiter.try_consume(amount_received_ld)?;
+        }
+        if let Some(rate_limiter) = ctx.accounts.oft_store.inbound_rate_limiter.as_mut() {
+            rate_limiter.refill(amount_received_ld)?;
+        }
 
+        if ctx.accounts.oft_store.launchpad_fee.is_some() {
+            let (
+                Some(fee_config),
+                Some(fee_schedule),
+                Some(vault),
+                Some(fee_collector_program),
+                Some(system_program),
+            ) = (
+                &ctx.accounts.fee_collector_config,
+                &ctx.accounts.fee_collector_schedule,
+                &ctx.accounts.fee_collector_vault,
+                &ctx.accounts.fee_collector_program,
+                &ctx.accounts.system_program,
+            )
+            else {
+                return Err(OFTError::LaunchpadFeeAccountsRequired.into());
+            };
+            fee_collector::cpi::collect_fee(
+                CpiContext::new(
+                    fee_collector_program.to_account_info(),
+                    fee_collector::cpi::accounts::CollectFee {
+                        from: ctx.accounts.signer.to_account_info(),
+                        fee_config: fee_config.to_account_info(),
+                        fee_schedule: fee_schedule.to_account_info(),
+                        vault: vault.to_account_info(),
+                        system_program: system_program.to_account_info(),
+                    },
+                ),
+                FeeAction::Bridge,
+            )?;
+        }
+
+        ctx.accounts.oft_store.add_fee(oft_fee_ld)?;
+        if params.referrer.is_some() {
+            // the referral share is taken from the oft fee, which is 0 in lamport fee mode
+            require!(
+                ctx.accounts.oft_store.lamport_fee_config.is_none(),
+                OFTError::ReferralNotSupported
+            );
+            let referrer = ctx.accounts.referrer.as_mut().ok_or(OFTError::InvalidReferrer)?;
+            ctx.accounts.oft_store.accrue_referral(referrer, oft_fee_ld)?;
+        }
         if ctx.accounts.oft_store.oft_type == OFTType::Adapter {
             // transfer all tokens to escrow with fee
-            ctx.accounts.oft_store.tvl_ld += amount_received_ld;
+            ctx.accounts.oft_store.tvl_ld = ctx
+                .accounts
+                .oft_store
+                .tvl_ld
+                .checked_add(amount_received_ld)
+                .ok_or(OFTError::MathOverflow)?;
+            let token_source =
+                ctx.accounts.token_source.as_ref().ok_or(OFTError::TokenSourceRequired)?;
             token_interface::transfer_checked(
                 CpiContext::new(
                     ctx.accounts.token_program.to_account_info(),
                     TransferChecked {
-                        from: ctx.accounts.token_source.to_account_info(),
+                        from: token_source.to_account_info(),
                         mint: ctx.accounts.token_mint.to_account_info(),
                         to: ctx.accounts.token_escrow.to_account_info(),
                         authority: ctx.accounts.signer.to_account_info(),
@@ -87,15 +270,77 @@ impl Send<'_> {
                 amount_sent_ld,
                 ctx.accounts.token_mint.decimals,
             )?;
+        } else if ctx.accounts.oft_store.oft_type == OFTType::WrappedNative {
+            // wrap all lamports into escrow with fee
+            ctx.accounts.oft_store.tvl_ld = ctx
+                .accounts
+                .oft_store
+                .tvl_ld
+                .checked_add(amount_received_ld)
+                .ok_or(OFTError::MathOverflow)?;
+            let system_program = ctx
+                .accounts
+                .system_program
+                .as_ref()
+                .ok_or(OFTError::WrappedNativeAccountsRequired)?;
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.signer.to_account_info(),
+                        to: ctx.accounts.token_escrow.to_account_info(),
+                    },
+                ),
+                amount_sent_ld,
+            )?;
+            token_interface::sync_native(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SyncNative { account: ctx.accounts.token_escrow.to_account_info() },
+            ))?;
+        } else if ctx.accounts.oft_store.oft_type == OFTType::Hybrid {
+            // lock up to the lock_cap_ld and burn the rest, the fee is transferred to escrow
+            let locked_ld = ctx.accounts.oft_store.lock_up_to_cap(amount_received_ld);
+            let token_source =
+                ctx.accounts.token_source.as_ref().ok_or(OFTError::TokenSourceRequired)?;
+            if amount_received_ld > locked_ld {
+                token_interface::burn(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        Burn {
+                            mint: ctx.accounts.token_mint.to_account_info(),
+                            from: token_source.to_account_info(),
+                            authority: ctx.accounts.signer.to_account_info(),
+                        },
+                    ),
+                    amount_received_ld - locked_ld,
+                )?;
+            }
+            if locked_ld + oft_fee_ld > 0 {
+                token_interface::transfer_checked(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: token_source.to_account_info(),
+                            mint: ctx.accounts.token_mint.to_account_info(),
+                            to: ctx.accounts.token_escrow.to_account_info(),
+                            authority: ctx.accounts.signer.to_account_info(),
+                        },
+                    ),
+                    locked_ld + oft_fee_ld,
+                    ctx.accounts.token_mint.decimals,
+                )?;
+            }
         } else {
             // Native type
+            let token_source =
+                ctx.accounts.token_source.as_ref().ok_or(OFTError::TokenSourceRequired)?;
             // burn
             token_interface::burn(
                 CpiContext::new(
                     ctx.accounts.token_program.to_account_info(),
                     Burn {
                         mint: ctx.accounts.token_mint.to_account_info(),
-                        from: ctx.accounts.token_source.to_account_info(),
+                        from: token_source.to_account_info(),
                         authority: ctx.accounts.signer.to_account_info(),
                     },
                 ),
@@ -108,7 +353,7 @@ impl Send<'_> {
                     CpiContext::new(
                         ctx.accounts.token_program.to_account_info(),
                         TransferChecked {
-                            from: ctx.accounts.token_source.to_account_info(),
+                            from: token_source.to_account_info(),
                             mint: ctx.accounts.token_mint.to_account_info(),
                             to: ctx.accounts.token_escrow.to_account_info(),
                             authority: ctx.accounts.signer.to_account_info(),
@@ -139,6 +384,7 @@ impl Send<'_> {
                     amount_sd,
                     ctx.accounts.signer.key(),
                     &params.compose_msg,
+                    params.to_token_account,
                 ),
                 options: ctx
                     .accounts
@@ -153,9 +399,14 @@ impl Send<'_> {
         emit_cpi!(OFTSent {
             guid: msg_receipt.guid,
             dst_eid: params.dst_eid,
-            from: ctx.accounts.token_source.key(),
+            from: ctx
+                .accounts
+                .token_source
+                .as_ref()
+                .map_or(ctx.accounts.signer.key(), |token_source| token_source.key()),
             amount_sent_ld,
-            amount_received_ld
+            amount_received_ld,
+            referrer: params.referrer,
         });
 
         Ok((msg_receipt, OFTReceipt { amount_sent_ld, amount_received_ld }))
@@ -172,4 +423,7 @@ pub struct SendParams {
     pub compose_msg: Option<Vec<u8>>,
     pub native_fee: u64,
     pub lz_token_fee: u64,
+    pub referrer: Option<Pubkey>, // must be registered with register_referrer
+    // credited instead of the associated token account of `to`, which must own it
+    pub to_token_account: Option<[u8; 32]>,
 }
@@ -3,21 +3,54 @@ use oapp::endpoint::instructions::SetDelegateParams;
 
 #[derive(Accounts)]
 pub struct SetOFTConfig<'info> {
-    pub admin: Signer<'info>,
+    /// admin, a holder of the role required by params, or anyone executing a ready proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
     #[account(
         mut,
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump,
-        has_one = admin @OFTError::Unauthorized
+        bump = oft_store.bump
     )]
     pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = proposer,
+        constraint = proposer.as_ref().is_some_and(|proposer| proposer.key() == proposal.proposer)
+            @OFTError::InvalidProposal
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+    /// CHECK: refunded the rent of the proposal. Only required with the proposal
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
 }
 
 impl SetOFTConfig<'_> {
     pub fn apply(ctx: &mut Context<SetOFTConfig>, params: &SetOFTConfigParams) -> Result<()> {
+        authorize_admin_action(
+            &ctx.accounts.oft_store,
+            ctx.accounts.signer.key(),
+            &ctx.accounts.role_assignment,
+            &ctx.accounts.proposal,
+            &AdminAction::SetOFTConfig(params.clone()),
+        )?;
         match params.clone() {
-            SetOFTConfigParams::Admin(admin) => {
-                ctx.accounts.oft_store.admin = admin;
+            SetOFTConfigParams::Admin(pending_admin) => {
+                // the new admin only takes effect once it calls accept_admin
+                ctx.accounts.oft_store.pending_admin = Some(pending_admin);
+                emit!(AdminTransferStarted {
+                    oft_store: ctx.accounts.oft_store.key(),
+                    admin: ctx.accounts.oft_store.admin,
+                    pending_admin,
+                });
+            },
+            SetOFTConfigParams::CancelAdminTransfer => {
+                let pending_admin =
+                    ctx.accounts.oft_store.pending_admin.take().ok_or(OFTError::NoPendingAdmin)?;
+                emit!(AdminTransferCancelled {
+                    oft_store: ctx.accounts.oft_store.key(),
+                    pending_admin,
+                });
             },
             SetOFTConfigParams::Delegate(delegate) => {
                 let oft_store_seed = ctx.accounts.oft_store.token_escrow.key();
@@ -35,26 +68,122 @@ impl SetOFTConfig<'_> {
                 require!(fee_bps < MAX_FEE_BASIS_POINTS, OFTError::InvalidFee);
                 ctx.accounts.oft_store.default_fee_bps = fee_bps;
             },
+            SetOFTConfigParams::DefaultFeeSchedule(fee_schedule) => {
+                if let Some(fee_schedule) = &fee_schedule {
+                    fee_schedule.validate()?;
+                }
+                ctx.accounts.oft_store.default_fee_schedule = fee_schedule;
+            },
+            SetOFTConfigParams::LamportFee(lamport_fee_config) => {
+                ctx.accounts.oft_store.lamport_fee_config = lamport_fee_config;
+            },
+            SetOFTConfigParams::LaunchpadFee(launchpad_fee) => {
+                ctx.accounts.oft_store.launchpad_fee = launchpad_fee;
+            },
             SetOFTConfigParams::Paused(paused) => {
                 ctx.accounts.oft_store.paused = paused;
             },
+            SetOFTConfigParams::BlocklistEnabled(blocklist_enabled) => {
+                ctx.accounts.oft_store.blocklist_enabled = blocklist_enabled;
+            },
             SetOFTConfigParams::Pauser(pauser) => {
                 ctx.accounts.oft_store.pauser = pauser;
             },
             SetOFTConfigParams::Unpauser(unpauser) => {
                 ctx.accounts.oft_store.unpauser = unpauser;
             },
+            SetOFTConfigParams::TimelockDelay(timelock_delay) => {
+                ctx.accounts.oft_store.timelock_delay = timelock_delay;
+            },
+            SetOFTConfigParams::RecoveryClaimTimeout(recovery_claim_timeout) => {
+                require!(recovery_claim_timeout > 0, OFTError::InvalidRecoveryClaimTimeout);
+                ctx.accounts.oft_store.recovery_claim_timeout = recovery_claim_timeout;
+            },
+            SetOFTConfigParams::LockCap(lock_cap_ld) => {
+                ctx.accounts.oft_store.lock_cap_ld = lock_cap_ld;
+            },
+            SetOFTConfigParams::Approvers { approvers, threshold } => {
+                require!(
+                    approvers.len() <= MAX_APPROVERS
+                        && threshold as usize <= approvers.len()
+                        && (approvers.is_empty() || threshold >= 1)
+                        && (1..approvers.len()).all(|i| !approvers[..i].contains(&approvers[i])),
+                    OFTError::InvalidApprovers
+                );
+                ctx.accounts.oft_store.approvers = approvers;
+                ctx.accounts.oft_store.approval_threshold = threshold;
+            },
+            SetOFTConfigParams::OutboundRateLimit(rate_limit_params) => {
+                RateLimiter::update(
+                    &mut ctx.accounts.oft_store.outbound_rate_limiter,
+                    &rate_limit_params,
+                )?;
+            },
+            SetOFTConfigParams::InboundRateLimit(rate_limit_params) => {
+                RateLimiter::update(
+                    &mut ctx.accounts.oft_store.inbound_rate_limiter,
+                    &rate_limit_params,
+                )?;
+            },
+            SetOFTConfigParams::ReferralFeeShare(referral_fee_share_bps) => {
+                require!(referral_fee_share_bps <= MAX_FEE_BASIS_POINTS, OFTError::InvalidFee);
+                ctx.accounts.oft_store.referral_fee_share_bps = referral_fee_share_bps;
+            },
+            SetOFTConfigParams::FeeRecipients { fee_recipients } => {
+                validate_fee_split(&fee_recipients)?;
+                ctx.accounts.oft_store.fee_recipients = fee_recipients;
+            },
         }
         Ok(())
     }
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub enum SetOFTConfigParams {
-    Admin(Pubkey),
+    Admin(Pubkey), // proposes a new admin, see accept_admin
+    CancelAdminTransfer,
     Delegate(Pubkey), // OApp delegate for the endpoint
     DefaultFee(u16),
+    DefaultFeeSchedule(Option<FeeSchedule>),
+    LamportFee(Option<LamportFeeConfig>),
+    LaunchpadFee(Option<LaunchpadFee>),
     Paused(bool),
+    BlocklistEnabled(bool),
     Pauser(Option<Pubkey>),
     Unpauser(Option<Pubkey>),
+    TimelockDelay(u64),
+    RecoveryClaimTimeout(u64),
+    LockCap(u64),
+    Approvers {
+        #[max_len(MAX_APPROVERS)]
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    OutboundRateLimit(Option<RateLimitParams>), // aggregate across all peers
+    InboundRateLimit(Option<RateLimitParams>),  // aggregate across all peers
+    ReferralFeeShare(u16),                      // bps of the oft fee
+    FeeRecipients {
+        #[max_len(MAX_FEE_RECIPIENTS)]
+        fee_recipients: Vec<FeeRecipient>,
+    },
+}
+
+impl SetOFTConfigParams {
+    /// The role that may apply this config besides the admin. None means admin only.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            SetOFTConfigParams::DefaultFee(_)
+            | SetOFTConfigParams::DefaultFeeSchedule(_)
+            | SetOFTConfigParams::LamportFee(_)
+            | SetOFTConfigParams::LaunchpadFee(_)
+            | SetOFTConfigParams::ReferralFeeShare(_) => Some(Role::FeeManager),
+            SetOFTConfigParams::Paused(true) => Some(Role::Pauser),
+            SetOFTConfigParams::Paused(false) => Some(Role::Unpauser),
+            SetOFTConfigParams::OutboundRateLimit(_) | SetOFTConfigParams::InboundRateLimit(_) => {
+                Some(Role::RateLimitManager)
+            },
+            SetOFTConfigParams::FeeRecipients { .. } => Some(Role::Treasurer),
+            _ => None,
+        }
+    }
 }
@@ -3,15 +3,21 @@ use crate::*;
 #[derive(Accounts)]
 #[instruction(params: SetPauseParams)]
 pub struct SetPause<'info> {
-    /// pauser or unpauser
+    /// pauser or unpauser, either configured on the oft_store or granted as a role
     pub signer: Signer<'info>,
     #[account(
         mut,
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
         bump = oft_store.bump,
-        constraint = is_valid_signer(signer.key(), &oft_store, params.paused) @OFTError::Unauthorized
+        constraint = is_valid_signer(
+            signer.key(),
+            &oft_store,
+            &role_assignment,
+            params.paused
+        ) @OFTError::Unauthorized
     )]
     pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
 impl SetPause<'_> {
@@ -26,10 +32,19 @@ pub struct SetPauseParams {
     pub paused: bool,
 }
 
-fn is_valid_signer(signer: Pubkey, oft_store: &OFTStore, paused: bool) -> bool {
-    if paused {
-        oft_store.pauser == Some(signer)
+fn is_valid_signer(
+    signer: Pubkey,
+    oft_store: &Account<OFTStore>,
+    role_assignment: &Option<Account<RoleAssignment>>,
+    paused: bool,
+) -> bool {
+    let (configured, role) = if paused {
+        (oft_store.pauser, Role::Pauser)
     } else {
-        oft_store.unpauser == Some(signer)
-    }
+        (oft_store.unpauser, Role::Unpauser)
+    };
+    configured == Some(signer)
+        || role_assignment
+            .as_ref()
+            .is_some_and(|assignment| assignment.grants(oft_store.key(), signer, role))
 }
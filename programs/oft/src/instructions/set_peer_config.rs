@@ -3,11 +3,12 @@ use crate::*;
 #[derive(Accounts)]
 #[instruction(params: SetPeerConfigParams)]
 pub struct SetPeerConfig<'info> {
+    /// admin, a holder of the role required by params, or anyone executing a ready proposal
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub signer: Signer<'info>,
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = signer,
         space = 8 + PeerConfig::INIT_SPACE,
         seeds = [PEER_SEED, oft_store.key().as_ref(), &params.remote_eid.to_be_bytes()],
         bump
@@ -15,25 +16,57 @@ pub struct SetPeerConfig<'info> {
     pub peer: Account<'info, PeerConfig>,
     #[account(
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump,
-        has_one = admin @OFTError::Unauthorized
+        bump = oft_store.bump
     )]
     pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = proposer,
+        constraint = proposer.as_ref().is_some_and(|proposer| proposer.key() == proposal.proposer)
+            @OFTError::InvalidProposal
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+    /// CHECK: refunded the rent of the proposal. Only required with the proposal
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
 }
 
 impl SetPeerConfig<'_> {
     pub fn apply(ctx: &mut Context<SetPeerConfig>, params: &SetPeerConfigParams) -> Result<()> {
+        authorize_admin_action(
+            &ctx.accounts.oft_store,
+            ctx.accounts.signer.key(),
+            &ctx.accounts.role_assignment,
+            &ctx.accounts.proposal,
+            &AdminAction::SetPeerConfig(params.clone()),
+        )?;
         match params.config.clone() {
             PeerConfigParam::PeerAddress(peer_address) => {
                 ctx.accounts.peer.peer_address = peer_address;
             },
+            PeerConfigParam::SvmPeer(svm_peer) => {
+                ctx.accounts.peer.svm_peer = svm_peer;
+            },
             PeerConfigParam::FeeBps(fee_bps) => {
                 if let Some(fee_bps) = fee_bps {
                     require!(fee_bps < MAX_FEE_BASIS_POINTS, OFTError::InvalidFee);
                 }
                 ctx.accounts.peer.fee_bps = fee_bps;
             },
+            PeerConfigParam::InboundFeeBps(inbound_fee_bps) => {
+                if let Some(inbound_fee_bps) = inbound_fee_bps {
+                    require!(inbound_fee_bps < MAX_FEE_BASIS_POINTS, OFTError::InvalidFee);
+                }
+                ctx.accounts.peer.inbound_fee_bps = inbound_fee_bps;
+            },
+            PeerConfigParam::FeeSchedule(fee_schedule) => {
+                if let Some(fee_schedule) = &fee_schedule {
+                    fee_schedule.validate()?;
+                }
+                ctx.accounts.peer.fee_schedule = fee_schedule;
+            },
             PeerConfigParam::EnforcedOptions { send, send_and_call } => {
                 oapp::options::assert_type_3(&send)?;
                 ctx.accounts.peer.enforced_options.send = send;
@@ -41,59 +74,86 @@ impl SetPeerConfig<'_> {
                 ctx.accounts.peer.enforced_options.send_and_call = send_and_call;
             },
             PeerConfigParam::OutboundRateLimit(rate_limit_params) => {
-                Self::update_rate_limiter(
+                RateLimiter::update(
                     &mut ctx.accounts.peer.outbound_rate_limiter,
                     &rate_limit_params,
                 )?;
             },
             PeerConfigParam::InboundRateLimit(rate_limit_params) => {
-                Self::update_rate_limiter(
+                RateLimiter::update(
                     &mut ctx.accounts.peer.inbound_rate_limiter,
                     &rate_limit_params,
                 )?;
             },
+            PeerConfigParam::SenderOutboundRateLimit(sender_rate_limit) => {
+                ctx.accounts.peer.sender_rate_limit = sender_rate_limit;
+            },
+            PeerConfigParam::MinSendAmount(min_send_ld) => {
+                ctx.accounts.peer.min_send_ld = min_send_ld;
+            },
+            PeerConfigParam::MaxSendAmount(max_send_ld) => {
+                ctx.accounts.peer.max_send_ld = max_send_ld;
+            },
         }
-        ctx.accounts.peer.bump = ctx.bumps.peer;
-        Ok(())
-    }
-
-    fn update_rate_limiter(
-        rate_limiter: &mut Option<RateLimiter>,
-        params: &Option<RateLimitParams>,
-    ) -> Result<()> {
-        if let Some(param) = params {
-            let mut limiter = rate_limiter.clone().unwrap_or_default();
-            if let Some(capacity) = param.capacity {
-                limiter.set_capacity(capacity)?;
-            }
-            if let Some(refill_rate) = param.refill_per_second {
-                limiter.set_rate(refill_rate)?;
-            }
-            *rate_limiter = Some(limiter);
-        } else {
-            *rate_limiter = None;
+        if let (Some(min_send_ld), Some(max_send_ld)) =
+            (ctx.accounts.peer.min_send_ld, ctx.accounts.peer.max_send_ld)
+        {
+            require!(min_send_ld <= max_send_ld, OFTError::InvalidSendAmountBounds);
         }
+        ctx.accounts.peer.bump = ctx.bumps.peer;
+        ctx.accounts.peer.version = PEER_CONFIG_VERSION;
         Ok(())
     }
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct SetPeerConfigParams {
     pub remote_eid: u32,
     pub config: PeerConfigParam,
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub enum PeerConfigParam {
     PeerAddress([u8; 32]),
     FeeBps(Option<u16>),
-    EnforcedOptions { send: Vec<u8>, send_and_call: Vec<u8> },
+    FeeSchedule(Option<FeeSchedule>),
+    InboundFeeBps(Option<u16>),
+    EnforcedOptions {
+        #[max_len(ENFORCED_OPTIONS_SEND_MAX_LEN)]
+        send: Vec<u8>,
+        #[max_len(ENFORCED_OPTIONS_SEND_AND_CALL_MAX_LEN)]
+        send_and_call: Vec<u8>,
+    },
     OutboundRateLimit(Option<RateLimitParams>),
     InboundRateLimit(Option<RateLimitParams>),
+    SenderOutboundRateLimit(Option<SenderRateLimit>),
+    MinSendAmount(Option<u64>),
+    MaxSendAmount(Option<u64>),
+    SvmPeer(bool),
+}
+
+impl PeerConfigParam {
+    /// The role that may apply this config besides the admin. None means admin only.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            PeerConfigParam::PeerAddress(_)
+            | PeerConfigParam::EnforcedOptions { .. }
+            | PeerConfigParam::SvmPeer(_) => Some(Role::PeerManager),
+            PeerConfigParam::FeeBps(_)
+            | PeerConfigParam::FeeSchedule(_)
+            | PeerConfigParam::InboundFeeBps(_) => Some(Role::FeeManager),
+            PeerConfigParam::OutboundRateLimit(_)
+            | PeerConfigParam::InboundRateLimit(_)
+            | PeerConfigParam::SenderOutboundRateLimit(_)
+            | PeerConfigParam::MinSendAmount(_)
+            | PeerConfigParam::MaxSendAmount(_) => Some(Role::RateLimitManager),
+        }
+    }
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct RateLimitParams {
     pub refill_per_second: Option<u64>,
     pub capacity: Option<u64>,
+    pub limiter_type: Option<RateLimiterType>,
 }
@@ -0,0 +1,86 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+/// Transfers tokens in the token_escrow that are neither tvl nor accrued fees, e.g. tokens
+/// sent to the escrow directly.
+#[derive(Accounts)]
+pub struct SweepSurplus<'info> {
+    /// admin, a treasurer, or anyone executing a ready proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = proposer,
+        constraint = proposer.as_ref().is_some_and(|proposer| proposer.key() == proposal.proposer)
+            @OFTError::InvalidProposal
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+    /// CHECK: refunded the rent of the proposal. Only required with the proposal
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
+    #[account(
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl SweepSurplus<'_> {
+    pub fn apply(ctx: &mut Context<SweepSurplus>) -> Result<()> {
+        authorize_admin_action(
+            &ctx.accounts.oft_store,
+            ctx.accounts.signer.key(),
+            &ctx.accounts.role_assignment,
+            &ctx.accounts.proposal,
+            &AdminAction::SweepSurplus { token_dest: ctx.accounts.token_dest.key() },
+        )?;
+        let amount_ld = ctx.accounts.oft_store.surplus_ld(ctx.accounts.token_escrow.amount)?;
+        require!(amount_ld > 0, OFTError::NoSurplus);
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            &ctx.accounts.token_escrow.key().to_bytes(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_escrow.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.oft_store.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds]),
+            amount_ld,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        emit!(SurplusSwept {
+            oft_store: ctx.accounts.oft_store.key(),
+            token_dest: ctx.accounts.token_dest.key(),
+            amount_ld,
+        });
+        Ok(())
+    }
+}
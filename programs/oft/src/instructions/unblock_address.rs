@@ -0,0 +1,30 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UnblockAddress<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [BLOCKLIST_SEED, oft_store.key().as_ref(), &blocked_address.address],
+        bump = blocked_address.bump
+    )]
+    pub blocked_address: Account<'info, BlockedAddress>,
+}
+
+impl UnblockAddress<'_> {
+    pub fn apply(ctx: &mut Context<UnblockAddress>) -> Result<()> {
+        emit!(AddressUnblocked {
+            oft_store: ctx.accounts.oft_store.key(),
+            address: ctx.accounts.blocked_address.address,
+        });
+        Ok(())
+    }
+}
@@ -3,13 +3,26 @@ use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, Tran
 
 #[derive(Accounts)]
 pub struct WithdrawFee<'info> {
-    pub admin: Signer<'info>,
+    /// admin, a treasurer, or anyone executing a ready proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
     #[account(
+        mut,
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump,
-        has_one = admin @OFTError::Unauthorized
+        bump = oft_store.bump
     )]
     pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = proposer,
+        constraint = proposer.as_ref().is_some_and(|proposer| proposer.key() == proposal.proposer)
+            @OFTError::InvalidProposal
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+    /// CHECK: refunded the rent of the proposal. Only required with the proposal
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
     #[account(
         address = oft_store.token_mint,
         mint::token_program = token_program
@@ -34,10 +47,17 @@ pub struct WithdrawFee<'info> {
 
 impl WithdrawFee<'_> {
     pub fn apply(ctx: &mut Context<WithdrawFee>, params: &WithdrawFeeParams) -> Result<()> {
-        require!(
-            ctx.accounts.token_escrow.amount - ctx.accounts.oft_store.tvl_ld >= params.fee_ld,
-            OFTError::InvalidFee
-        );
+        authorize_admin_action(
+            &ctx.accounts.oft_store,
+            ctx.accounts.signer.key(),
+            &ctx.accounts.role_assignment,
+            &ctx.accounts.proposal,
+            &AdminAction::WithdrawFee {
+                token_dest: ctx.accounts.token_dest.key(),
+                params: params.clone(),
+            },
+        )?;
+        ctx.accounts.oft_store.take_fee(params.fee_ld)?;
         let seeds: &[&[u8]] = &[
             OFT_SEED,
             &ctx.accounts.token_escrow.key().to_bytes(),
@@ -61,7 +81,7 @@ impl WithdrawFee<'_> {
     }
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct WithdrawFeeParams {
     pub fee_ld: u64,
 }
@@ -0,0 +1,66 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawLamportFee<'info> {
+    /// admin, a treasurer, or anyone executing a ready proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        close = proposer,
+        constraint = proposer.as_ref().is_some_and(|proposer| proposer.key() == proposal.proposer)
+            @OFTError::InvalidProposal
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+    /// CHECK: refunded the rent of the proposal. Only required with the proposal
+    #[account(mut)]
+    pub proposer: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [LAMPORT_FEE_VAULT_SEED, oft_store.key().as_ref()],
+        bump = lamport_fee_vault.bump
+    )]
+    pub lamport_fee_vault: Account<'info, LamportFeeVault>,
+    /// CHECK: any account can receive lamports
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+impl WithdrawLamportFee<'_> {
+    pub fn apply(
+        ctx: &mut Context<WithdrawLamportFee>,
+        params: &WithdrawLamportFeeParams,
+    ) -> Result<()> {
+        authorize_admin_action(
+            &ctx.accounts.oft_store,
+            ctx.accounts.signer.key(),
+            &ctx.accounts.role_assignment,
+            &ctx.accounts.proposal,
+            &AdminAction::WithdrawLamportFee {
+                receiver: ctx.accounts.receiver.key(),
+                params: params.clone(),
+            },
+        )?;
+        // the vault keeps its rent-exempt minimum
+        let vault = ctx.accounts.lamport_fee_vault.to_account_info();
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(vault.data_len());
+        require!(
+            vault.lamports().saturating_sub(rent_exempt_lamports) >= params.lamports,
+            OFTError::InvalidFee
+        );
+        **vault.try_borrow_mut_lamports()? -= params.lamports;
+        **ctx.accounts.receiver.try_borrow_mut_lamports()? += params.lamports;
+        Ok(())
+    }
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct WithdrawLamportFeeParams {
+    pub lamports: u64,
+}
@@ -24,6 +24,16 @@ declare_id!(Pubkey::new_from_array(program_id_from_env!(
 
 pub const OFT_SEED: &[u8] = b"OFT";
 pub const PEER_SEED: &[u8] = b"Peer";
+pub const ROLE_SEED: &[u8] = b"Role";
+pub const PROPOSAL_SEED: &[u8] = b"Proposal";
+pub const SENDER_RATE_LIMITER_SEED: &[u8] = b"SenderRateLimiter";
+pub const FEE_EXEMPTION_SEED: &[u8] = b"FeeExemption";
+pub const REFERRER_SEED: &[u8] = b"Referrer";
+pub const LAMPORT_FEE_VAULT_SEED: &[u8] = b"LamportFeeVault";
+pub const BLOCKLIST_SEED: &[u8] = b"Blocklist";
+pub const RECOVERY_CLAIM_SEED: &[u8] = b"RecoveryClaim";
+pub const QUARANTINE_SEED: &[u8] = b"Quarantine";
+pub const UNWRAP_SEED: &[u8] = b"Unwrap";
 pub const ENFORCED_OPTIONS_SEED: &[u8] = b"EnforcedOptions";
 pub const LZ_RECEIVE_TYPES_SEED: &[u8] = oapp::LZ_RECEIVE_TYPES_SEED;
 
@@ -39,6 +49,10 @@ pub mod oft {
         InitOFT::apply(&mut ctx, &params)
     }
 
+    pub fn launch_token(mut ctx: Context<LaunchToken>, params: LaunchTokenParams) -> Result<()> {
+        LaunchToken::apply(&mut ctx, &params)
+    }
+
     // ============================== Admin ==============================
     pub fn set_oft_config(
         mut ctx: Context<SetOFTConfig>,
@@ -47,6 +61,44 @@ pub mod oft {
         SetOFTConfig::apply(&mut ctx, &params)
     }
 
+    pub fn migrate_oft_store(mut ctx: Context<MigrateOFTStore>) -> Result<()> {
+        MigrateOFTStore::apply(&mut ctx)
+    }
+
+    pub fn migrate_peer_config(
+        mut ctx: Context<MigratePeerConfig>,
+        params: MigratePeerConfigParams,
+    ) -> Result<()> {
+        MigratePeerConfig::apply(&mut ctx, &params)
+    }
+
+    pub fn accept_admin(mut ctx: Context<AcceptAdmin>) -> Result<()> {
+        AcceptAdmin::apply(&mut ctx)
+    }
+
+    pub fn grant_role(mut ctx: Context<GrantRole>, params: GrantRoleParams) -> Result<()> {
+        GrantRole::apply(&mut ctx, &params)
+    }
+
+    pub fn revoke_role(mut ctx: Context<RevokeRole>) -> Result<()> {
+        RevokeRole::apply(&mut ctx)
+    }
+
+    pub fn create_proposal(
+        mut ctx: Context<CreateProposal>,
+        params: CreateProposalParams,
+    ) -> Result<()> {
+        CreateProposal::apply(&mut ctx, &params)
+    }
+
+    pub fn approve_proposal(mut ctx: Context<ApproveProposal>) -> Result<()> {
+        ApproveProposal::apply(&mut ctx)
+    }
+
+    pub fn cancel_proposal(mut ctx: Context<CancelProposal>) -> Result<()> {
+        CancelProposal::apply(&mut ctx)
+    }
+
     pub fn set_peer_config(
         mut ctx: Context<SetPeerConfig>,
         params: SetPeerConfigParams,
@@ -58,10 +110,61 @@ pub mod oft {
         SetPause::apply(&mut ctx, &params)
     }
 
+    pub fn add_fee_exemption(
+        mut ctx: Context<AddFeeExemption>,
+        params: AddFeeExemptionParams,
+    ) -> Result<()> {
+        AddFeeExemption::apply(&mut ctx, &params)
+    }
+
+    pub fn remove_fee_exemption(mut ctx: Context<RemoveFeeExemption>) -> Result<()> {
+        RemoveFeeExemption::apply(&mut ctx)
+    }
+
+    pub fn distribute_fees<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    ) -> Result<()> {
+        DistributeFees::apply(&mut ctx)
+    }
+
+    pub fn sweep_surplus(mut ctx: Context<SweepSurplus>) -> Result<()> {
+        SweepSurplus::apply(&mut ctx)
+    }
+
+    pub fn register_referrer(mut ctx: Context<RegisterReferrer>) -> Result<()> {
+        RegisterReferrer::apply(&mut ctx)
+    }
+
+    pub fn claim_referral_rewards(mut ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        ClaimReferralRewards::apply(&mut ctx)
+    }
+
+    pub fn block_address(mut ctx: Context<BlockAddress>, params: BlockAddressParams) -> Result<()> {
+        BlockAddress::apply(&mut ctx, &params)
+    }
+
+    pub fn unblock_address(mut ctx: Context<UnblockAddress>) -> Result<()> {
+        UnblockAddress::apply(&mut ctx)
+    }
+
+    pub fn release_quarantine(
+        mut ctx: Context<ReleaseQuarantine>,
+        params: ReleaseQuarantineParams,
+    ) -> Result<()> {
+        ReleaseQuarantine::apply(&mut ctx, &params)
+    }
+
     pub fn withdraw_fee(mut ctx: Context<WithdrawFee>, params: WithdrawFeeParams) -> Result<()> {
         WithdrawFee::apply(&mut ctx, &params)
     }
 
+    pub fn withdraw_lamport_fee(
+        mut ctx: Context<WithdrawLamportFee>,
+        params: WithdrawLamportFeeParams,
+    ) -> Result<()> {
+        WithdrawLamportFee::apply(&mut ctx, &params)
+    }
+
     // ============================== Public ==============================
 
     pub fn quote_oft(ctx: Context<QuoteOFT>, params: QuoteOFTParams) -> Result<QuoteOFTResult> {
@@ -83,6 +186,17 @@ pub mod oft {
         LzReceive::apply(&mut ctx, &params)
     }
 
+    pub fn recover_lz_receive(
+        mut ctx: Context<RecoverLzReceive>,
+        params: LzReceiveParams,
+    ) -> Result<()> {
+        RecoverLzReceive::apply(&mut ctx, &params)
+    }
+
+    pub fn claim_recovered(mut ctx: Context<ClaimRecovered>) -> Result<()> {
+        ClaimRecovered::apply(&mut ctx)
+    }
+
     pub fn lz_receive_types(
         ctx: Context<LzReceiveTypes>,
         params: LzReceiveParams,
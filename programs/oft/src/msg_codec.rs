@@ -3,20 +3,33 @@ use crate::*;
 const SEND_TO_OFFSET: usize = 0;
 const SEND_AMOUNT_SD_OFFSET: usize = 32;
 const COMPOSE_MSG_OFFSET: usize = 40;
+const TO_TOKEN_ACCOUNT_OFFSET: usize = 40;
+// From an SVM peer, a message of this length carries the token account of send_to to credit,
+// instead of its associated token account. Composed messages are longer, as an empty compose msg
+// is encoded without one like on EVM. Other peers, and SVM peers of the initial release, can
+// send composed messages of this length, so it's only decoded for peers marked as svm_peer.
+const TO_TOKEN_ACCOUNT_MSG_LEN: usize = 72;
 
 pub fn encode(
     send_to: [u8; 32],
     amount_sd: u64,
     sender: Pubkey,
     compose_msg: &Option<Vec<u8>>,
+    to_token_account: Option<[u8; 32]>,
 ) -> Vec<u8> {
-    if let Some(msg) = compose_msg {
+    if let Some(msg) = compose_msg.as_ref().filter(|msg| !msg.is_empty()) {
         let mut encoded = Vec::with_capacity(72 + msg.len()); // 32 + 8 + 32
         encoded.extend_from_slice(&send_to);
         encoded.extend_from_slice(&amount_sd.to_be_bytes());
         encoded.extend_from_slice(sender.to_bytes().as_ref());
         encoded.extend_from_slice(&msg);
         encoded
+    } else if let Some(token_account) = to_token_account {
+        let mut encoded = Vec::with_capacity(TO_TOKEN_ACCOUNT_MSG_LEN); // 32 + 8 + 32
+        encoded.extend_from_slice(&send_to);
+        encoded.extend_from_slice(&amount_sd.to_be_bytes());
+        encoded.extend_from_slice(&token_account);
+        encoded
     } else {
         let mut encoded = Vec::with_capacity(40); // 32 + 8
         encoded.extend_from_slice(&send_to);
@@ -37,8 +50,18 @@ pub fn amount_sd(message: &[u8]) -> u64 {
     u64::from_be_bytes(amount_sd_bytes)
 }
 
-pub fn compose_msg(message: &[u8]) -> Option<Vec<u8>> {
-    if message.len() > COMPOSE_MSG_OFFSET {
+pub fn to_token_account(message: &[u8], svm_peer: bool) -> Option<[u8; 32]> {
+    if svm_peer && message.len() == TO_TOKEN_ACCOUNT_MSG_LEN {
+        let mut token_account = [0; 32];
+        token_account.copy_from_slice(&message[TO_TOKEN_ACCOUNT_OFFSET..]);
+        Some(token_account)
+    } else {
+        None
+    }
+}
+
+pub fn compose_msg(message: &[u8], svm_peer: bool) -> Option<Vec<u8>> {
+    if message.len() > COMPOSE_MSG_OFFSET && to_token_account(message, svm_peer).is_none() {
         Some(message[COMPOSE_MSG_OFFSET..].to_vec())
     } else {
         None
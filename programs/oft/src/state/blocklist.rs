@@ -0,0 +1,17 @@
+use crate::*;
+
+/// BlockedAddress blocks an address from sending and receiving through the oft_store.
+/// The address is 32 bytes so that remote addresses can be blocked as well.
+#[account]
+#[derive(InitSpace)]
+pub struct BlockedAddress {
+    pub oft_store: Pubkey,
+    pub address: [u8; 32],
+    pub reason_code: Option<u16>,
+    pub bump: u8,
+}
+
+/// The account must be the BlockedAddress PDA of the address, it is blocked if it exists.
+pub fn is_blocked(blocked_address: &AccountInfo) -> bool {
+    blocked_address.owner == &ID && !blocked_address.data_is_empty()
+}
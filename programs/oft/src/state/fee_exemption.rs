@@ -0,0 +1,18 @@
+use crate::*;
+
+/// FeeExemption waives the OFT fee for sends from a wallet, towards a single peer or,
+/// with a dst_eid of 0, towards every peer.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeExemption {
+    pub oft_store: Pubkey,
+    pub wallet: Pubkey,
+    pub dst_eid: u32,
+    pub bump: u8,
+}
+
+impl FeeExemption {
+    pub fn applies_to(&self, oft_store: Pubkey, dst_eid: u32) -> bool {
+        self.oft_store == oft_store && (self.dst_eid == 0 || self.dst_eid == dst_eid)
+    }
+}
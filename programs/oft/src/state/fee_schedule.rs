@@ -0,0 +1,67 @@
+use crate::*;
+
+pub const MAX_FEE_TIERS: usize = 8;
+
+/// FeeSchedule charges the fee_bps of the highest tier whose min_amount_ld is not above the
+/// amount, then bounds the fee by min_fee_ld and max_fee_ld.
+///
+/// The fee of a send is resolved in this order: the peer fee_schedule, the peer fee_bps,
+/// the oft_store default_fee_schedule, then the oft_store default_fee_bps.
+#[derive(InitSpace, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct FeeSchedule {
+    #[max_len(MAX_FEE_TIERS)]
+    pub tiers: Vec<FeeTier>, // sorted by min_amount_ld, starting at 0
+    pub min_fee_ld: Option<u64>,
+    pub max_fee_ld: Option<u64>,
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct FeeTier {
+    pub min_amount_ld: u64,
+    pub fee_bps: u16,
+}
+
+impl FeeSchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.tiers.is_empty()
+                && self.tiers.len() <= MAX_FEE_TIERS
+                && self.tiers[0].min_amount_ld == 0
+                && self.tiers.windows(2).all(|w| w[0].min_amount_ld < w[1].min_amount_ld)
+                && self.tiers.iter().all(|tier| tier.fee_bps < MAX_FEE_BASIS_POINTS),
+            OFTError::InvalidFee
+        );
+        if let (Some(min_fee_ld), Some(max_fee_ld)) = (self.min_fee_ld, self.max_fee_ld) {
+            require!(min_fee_ld <= max_fee_ld, OFTError::InvalidFee);
+        }
+        Ok(())
+    }
+
+    pub fn calculate_fee(&self, amount_ld: u64) -> u64 {
+        let fee_bps = self
+            .tiers
+            .iter()
+            .take_while(|tier| tier.min_amount_ld <= amount_ld)
+            .last()
+            .map_or(0, |tier| tier.fee_bps);
+        let mut fee = calculate_bps_fee(amount_ld, fee_bps);
+        if let Some(min_fee_ld) = self.min_fee_ld {
+            fee = std::cmp::max(fee, min_fee_ld);
+        }
+        if let Some(max_fee_ld) = self.max_fee_ld {
+            fee = std::cmp::min(fee, max_fee_ld);
+        }
+        // the fee can never exceed the amount itself
+        std::cmp::min(fee, amount_ld)
+    }
+}
+
+pub fn calculate_bps_fee(amount_ld: u64, fee_bps: u16) -> u64 {
+    if fee_bps == 0 || amount_ld == 0 {
+        0
+    } else {
+        // amount_ld * fee_bps / MAX_FEE_BASIS_POINTS
+        let fee = (amount_ld as u128) * (fee_bps as u128);
+        (fee / MAX_FEE_BASIS_POINTS as u128) as u64
+    }
+}
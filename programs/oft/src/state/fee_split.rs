@@ -0,0 +1,36 @@
+use crate::*;
+
+pub const MAX_FEE_RECIPIENTS: usize = 8;
+
+/// FeeRecipient receives fee_bps of the accrued fees paid out by distribute_fees.
+/// The shares of all recipients of an oft_store add up to MAX_FEE_BASIS_POINTS.
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct FeeRecipient {
+    pub token_account: Pubkey,
+    pub fee_bps: u16,
+}
+
+impl FeeRecipient {
+    /// Rounds down, the remainder stays in escrow until the next distribution.
+    pub fn share_of(&self, fee_ld: u64) -> u64 {
+        calculate_bps_fee(fee_ld, self.fee_bps)
+    }
+}
+
+/// An empty split disables distribute_fees.
+pub fn validate_fee_split(recipients: &[FeeRecipient]) -> Result<()> {
+    if recipients.is_empty() {
+        return Ok(());
+    }
+    require!(
+        recipients.len() <= MAX_FEE_RECIPIENTS
+            && recipients.iter().all(|recipient| recipient.fee_bps > 0)
+            && recipients.iter().map(|recipient| recipient.fee_bps as u32).sum::<u32>()
+                == MAX_FEE_BASIS_POINTS as u32
+            && (1..recipients.len()).all(|i| {
+                recipients[..i].iter().all(|r| r.token_account != recipients[i].token_account)
+            }),
+        OFTError::InvalidFeeSplit
+    );
+    Ok(())
+}
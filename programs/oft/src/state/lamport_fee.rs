@@ -0,0 +1,32 @@
+use crate::*;
+
+/// LamportFeeConfig charges the oft fee in lamports instead of tokens. The token fee resolved
+/// for the send is converted at lamports_per_token, the admin-set price of one whole token,
+/// and added to flat_lamports. The lamports are paid by the sender into the LamportFeeVault.
+#[derive(InitSpace, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct LamportFeeConfig {
+    pub flat_lamports: u64,
+    pub lamports_per_token: u64,
+}
+
+impl LamportFeeConfig {
+    pub fn calculate_fee(&self, token_fee_ld: u64, decimals: u8) -> u64 {
+        let converted = (token_fee_ld as u128) * (self.lamports_per_token as u128)
+            / 10u128.pow(decimals as u32);
+        self.flat_lamports.saturating_add(converted.try_into().unwrap_or(u64::MAX))
+    }
+}
+
+/// LaunchpadFee is collected in lamports on every send through a CPI into the fee_collector
+/// program, at the Bridge price of the fee schedule of the fee_config.
+#[derive(InitSpace, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct LaunchpadFee {
+    pub fee_config: Pubkey,
+}
+
+/// LamportFeeVault holds the lamport fees of an oft_store until withdraw_lamport_fee.
+#[account]
+#[derive(InitSpace)]
+pub struct LamportFeeVault {
+    pub bump: u8,
+}
@@ -0,0 +1,126 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+// Layouts of the initial release, read by migrate_oft_store and migrate_peer_config.
+// Accounts of the initial release were allocated with exactly these sizes.
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct OFTStoreV0 {
+    pub oft_type: OFTType,
+    pub ld2sd_rate: u64,
+    pub token_mint: Pubkey,
+    pub token_escrow: Pubkey,
+    pub endpoint_program: Pubkey,
+    pub bump: u8,
+    pub tvl_ld: u64,
+    pub admin: Pubkey,
+    pub default_fee_bps: u16,
+    pub paused: bool,
+    pub pauser: Option<Pubkey>,
+    pub unpauser: Option<Pubkey>,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct PeerConfigV0 {
+    pub peer_address: [u8; 32],
+    pub enforced_options: EnforcedOptions,
+    pub outbound_rate_limiter: Option<RateLimiterV0>,
+    pub inbound_rate_limiter: Option<RateLimiterV0>,
+    pub fee_bps: Option<u16>,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct RateLimiterV0 {
+    pub capacity: u64,
+    pub tokens: u64,
+    pub refill_per_second: u64,
+    pub last_refill_time: u64,
+}
+
+impl From<OFTStoreV0> for OFTStore {
+    fn from(legacy: OFTStoreV0) -> Self {
+        OFTStore {
+            oft_type: legacy.oft_type,
+            ld2sd_rate: legacy.ld2sd_rate,
+            token_mint: legacy.token_mint,
+            token_escrow: legacy.token_escrow,
+            endpoint_program: legacy.endpoint_program,
+            bump: legacy.bump,
+            tvl_ld: legacy.tvl_ld,
+            admin: legacy.admin,
+            default_fee_bps: legacy.default_fee_bps,
+            paused: legacy.paused,
+            pauser: legacy.pauser,
+            unpauser: legacy.unpauser,
+            version: OFT_STORE_VERSION,
+            recovery_claim_timeout: DEFAULT_RECOVERY_CLAIM_TIMEOUT,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PeerConfigV0> for PeerConfig {
+    fn from(legacy: PeerConfigV0) -> Self {
+        PeerConfig {
+            peer_address: legacy.peer_address,
+            enforced_options: legacy.enforced_options,
+            outbound_rate_limiter: legacy.outbound_rate_limiter.map(RateLimiter::from),
+            inbound_rate_limiter: legacy.inbound_rate_limiter.map(RateLimiter::from),
+            fee_bps: legacy.fee_bps,
+            bump: legacy.bump,
+            version: PEER_CONFIG_VERSION,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<RateLimiterV0> for RateLimiter {
+    fn from(legacy: RateLimiterV0) -> Self {
+        RateLimiter {
+            capacity: legacy.capacity,
+            tokens: legacy.tokens,
+            refill_per_second: legacy.refill_per_second,
+            last_refill_time: legacy.last_refill_time,
+            ..Default::default()
+        }
+    }
+}
+
+/// Reallocs an account of the initial release to the current layout and writes the account
+/// returned by migrate, the payer funds the additional rent. Fails with AlreadyMigrated unless
+/// the account has the legacy size and the discriminator of T.
+pub(crate) fn migrate_account<'info, T, V>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    migrate: impl FnOnce(V) -> Result<T>,
+) -> Result<()>
+where
+    T: AccountSerialize + Discriminator + Space,
+    V: AnchorDeserialize + Space,
+{
+    let legacy = {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() == 8 + V::INIT_SPACE && data[..8] == T::DISCRIMINATOR,
+            OFTError::AlreadyMigrated
+        );
+        V::deserialize(&mut &data[8..])?
+    };
+    let migrated = migrate(legacy)?;
+
+    let space = 8 + T::INIT_SPACE;
+    let rent_lamports = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if rent_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            rent_lamports,
+        )?;
+    }
+    account.realloc(space, true)?;
+    migrated.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
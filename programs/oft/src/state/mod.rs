@@ -1,5 +1,29 @@
+pub mod blocklist;
+pub mod fee_exemption;
+pub mod fee_schedule;
+pub mod fee_split;
+pub mod lamport_fee;
+pub mod legacy;
 pub mod oft;
 pub mod peer_config;
+pub mod proposal;
+pub mod quarantine_record;
+pub mod recovery_claim;
+pub mod referrer;
+pub mod role;
+pub mod sender_rate_limiter;
 
+pub use blocklist::*;
+pub use fee_exemption::*;
+pub use fee_schedule::*;
+pub use fee_split::*;
+pub use lamport_fee::*;
+pub use legacy::*;
 pub use oft::*;
 pub use peer_config::*;
+pub use proposal::*;
+pub use quarantine_record::*;
+pub use recovery_claim::*;
+pub use referrer::*;
+pub use role::*;
+pub use sender_rate_limiter::*;
@@ -1,7 +1,10 @@
 use crate::*;
 
+/// Bumped when fields are appended to OFTStore, see migrate_oft_store.
+pub const OFT_STORE_VERSION: u8 = 1;
+
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct OFTStore {
     // immutable
     pub oft_type: OFTType,
@@ -18,12 +21,39 @@ pub struct OFTStore {
     pub paused: bool,
     pub pauser: Option<Pubkey>,
     pub unpauser: Option<Pubkey>,
+    // Appended after the initial release, which stores are migrated from with
+    // migrate_oft_store. New fields must only be appended so existing stores stay readable.
+    pub version: u8,
+    pub pending_admin: Option<Pubkey>, // set by the admin, must be accepted by the pending admin
+    pub timelock_delay: u64,           // in seconds. 0 means config changes apply immediately
+    #[max_len(MAX_APPROVERS)]
+    pub approvers: Vec<Pubkey>,
+    pub approval_threshold: u8, // approvals a proposal needs. 0 means no approvals are needed
+    // aggregate rate limiters across all peers, applied on top of the peer rate limiters
+    pub outbound_rate_limiter: Option<RateLimiter>,
+    pub inbound_rate_limiter: Option<RateLimiter>,
+    pub default_fee_schedule: Option<FeeSchedule>, // takes precedence over default_fee_bps
+    #[max_len(MAX_FEE_RECIPIENTS)]
+    pub fee_recipients: Vec<FeeRecipient>, // paid out by distribute_fees
+    pub accrued_fee_ld: u64, // fees held in token_escrow, not yet withdrawn or distributed
+    pub referral_fee_share_bps: u16, // share of the oft fee accrued to the referrer of a send
+    pub accrued_referral_ld: u64, // referral rewards held in token_escrow, not yet claimed
+    pub lamport_fee_config: Option<LamportFeeConfig>, // if set, the oft fee is paid in lamports
+    pub launchpad_fee: Option<LaunchpadFee>, // charged on top of the oft fee
+    pub blocklist_enabled: bool, // checks send and lz_receive against the blocklist
+    pub quarantined_ld: u64, // total of the QuarantineRecords, held in token_escrow
+    pub claimable_ld: u64,   // owed to recovery claims, held in token_escrow
+    pub recovery_claim_timeout: u64, // in seconds, before the admin can claim. Never 0
+    pub lock_cap_ld: u64,    // Hybrid only, the tvl_ld up to which send locks
 }
 
-#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+#[derive(InitSpace, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub enum OFTType {
+    #[default]
     Native,
     Adapter,
+    WrappedNative, // bridges SOL, held as wSOL in token_escrow
+    Hybrid,        // locks up to lock_cap_ld and burns the rest, unlocks before minting
 }
 
 impl OFTStore {
@@ -38,6 +68,63 @@ impl OFTStore {
     pub fn remove_dust(&self, amount_ld: u64) -> u64 {
         amount_ld - amount_ld % self.ld2sd_rate
     }
+
+    /// The part of the token_escrow balance that is accounted for.
+    pub fn reserved_ld(&self) -> Result<u64> {
+        self.tvl_ld
+            .checked_add(self.accrued_fee_ld)
+            .and_then(|reserved_ld| reserved_ld.checked_add(self.accrued_referral_ld))
+            .and_then(|reserved_ld| reserved_ld.checked_add(self.quarantined_ld))
+            .and_then(|reserved_ld| reserved_ld.checked_add(self.claimable_ld))
+            .ok_or(error!(OFTError::MathOverflow))
+    }
+
+    /// Tokens in the token_escrow that are neither tvl nor fees, e.g. sent to it directly.
+    /// Fails if the token_escrow holds less than is reserved, as there is nothing to sweep then.
+    pub fn surplus_ld(&self, escrow_amount_ld: u64) -> Result<u64> {
+        escrow_amount_ld
+            .checked_sub(self.reserved_ld()?)
+            .ok_or(error!(OFTError::EscrowBelowReserved))
+    }
+
+    /// Hybrid only. Locks amount_ld up to the lock_cap_ld, returns the locked amount, the rest
+    /// is burned.
+    pub fn lock_up_to_cap(&mut self, amount_ld: u64) -> u64 {
+        let locked_ld = amount_ld.min(self.lock_cap_ld.saturating_sub(self.tvl_ld));
+        self.tvl_ld += locked_ld;
+        locked_ld
+    }
+
+    /// Hybrid only. Unlocks amount_ld up to the tvl_ld, returns the unlocked amount, the rest
+    /// is minted.
+    pub fn unlock_up_to_tvl(&mut self, amount_ld: u64) -> u64 {
+        let unlocked_ld = amount_ld.min(self.tvl_ld);
+        self.tvl_ld -= unlocked_ld;
+        unlocked_ld
+    }
+
+    pub fn add_fee(&mut self, fee_ld: u64) -> Result<()> {
+        self.accrued_fee_ld =
+            self.accrued_fee_ld.checked_add(fee_ld).ok_or(OFTError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Moves the referral share out of an oft fee that was just added.
+    pub fn accrue_referral(&mut self, referrer: &mut Referrer, oft_fee_ld: u64) -> Result<()> {
+        let referral_ld = calculate_bps_fee(oft_fee_ld, self.referral_fee_share_bps);
+        self.take_fee(referral_ld)?;
+        self.accrued_referral_ld =
+            self.accrued_referral_ld.checked_add(referral_ld).ok_or(OFTError::MathOverflow)?;
+        referrer.accrued_ld =
+            referrer.accrued_ld.checked_add(referral_ld).ok_or(OFTError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn take_fee(&mut self, fee_ld: u64) -> Result<()> {
+        self.accrued_fee_ld =
+            self.accrued_fee_ld.checked_sub(fee_ld).ok_or(OFTError::InvalidFee)?;
+        Ok(())
+    }
 }
 
 /// LzReceiveTypesAccounts includes accounts that are used in the LzReceiveTypes
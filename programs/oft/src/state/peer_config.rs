@@ -3,8 +3,11 @@ use crate::*;
 pub const ENFORCED_OPTIONS_SEND_MAX_LEN: usize = 512;
 pub const ENFORCED_OPTIONS_SEND_AND_CALL_MAX_LEN: usize = 1024;
 
+/// Bumped when fields are appended to PeerConfig, see migrate_peer_config.
+pub const PEER_CONFIG_VERSION: u8 = 1;
+
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct PeerConfig {
     pub peer_address: [u8; 32],
     pub enforced_options: EnforcedOptions,
@@ -12,6 +15,27 @@ pub struct PeerConfig {
     pub inbound_rate_limiter: Option<RateLimiter>,
     pub fee_bps: Option<u16>,
     pub bump: u8,
+    // Appended after the initial release, which peers are migrated from with
+    // migrate_peer_config. New fields must only be appended so existing peers stay readable.
+    pub version: u8,
+    pub sender_rate_limit: Option<SenderRateLimit>, // per sender, on top of outbound_rate_limiter
+    pub min_send_ld: Option<u64>,
+    pub max_send_ld: Option<u64>,
+    pub fee_schedule: Option<FeeSchedule>, // takes precedence over fee_bps
+    pub inbound_fee_bps: Option<u16>, // retained in token_escrow, composed messages are rejected
+    pub svm_peer: bool, // the peer is an SVM OFT that decodes to_token_account messages
+}
+
+impl PeerConfig {
+    pub fn check_send_amount(&self, amount_ld: u64) -> Result<()> {
+        if let Some(min_send_ld) = self.min_send_ld {
+            require!(amount_ld >= min_send_ld, OFTError::AmountBelowMinimum);
+        }
+        if let Some(max_send_ld) = self.max_send_ld {
+            require!(amount_ld <= max_send_ld, OFTError::AmountAboveMaximum);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Default, AnchorSerialize, AnchorDeserialize, InitSpace)]
@@ -20,9 +44,56 @@ pub struct RateLimiter {
     pub tokens: u64,
     pub refill_per_second: u64,
     pub last_refill_time: u64,
+    pub limiter_type: RateLimiterType,
+    // amounts consumed per slot, only used by RollingWindow
+    pub window_usage: [u64; RATE_LIMIT_WINDOW_SLOTS],
+}
+
+pub const RATE_LIMIT_WINDOW_SLOTS: usize = 12;
+
+#[derive(Clone, Default, AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq, Eq)]
+pub enum RateLimiterType {
+    /// `tokens` refill at `refill_per_second` up to `capacity`
+    #[default]
+    TokenBucket,
+    /// at most `capacity` within any `window_seconds` period. Amounts are tracked in
+    /// slots of window_seconds / (RATE_LIMIT_WINDOW_SLOTS - 1), so they can be counted
+    /// up to one slot longer than the window but never shorter.
+    RollingWindow { window_seconds: u64 },
 }
 
 impl RateLimiter {
+    /// Applies the params to an optional rate limiter. None params remove the rate limiter.
+    pub fn update(
+        rate_limiter: &mut Option<RateLimiter>,
+        params: &Option<RateLimitParams>,
+    ) -> Result<()> {
+        if let Some(param) = params {
+            let mut limiter = rate_limiter.clone().unwrap_or_default();
+            if let Some(limiter_type) = param.limiter_type.clone() {
+                limiter.set_type(limiter_type)?;
+            }
+            if let Some(capacity) = param.capacity {
+                limiter.set_capacity(capacity)?;
+            }
+            if let Some(refill_rate) = param.refill_per_second {
+                limiter.set_rate(refill_rate)?;
+            }
+            *rate_limiter = Some(limiter);
+        } else {
+            *rate_limiter = None;
+        }
+        Ok(())
+    }
+
+    pub fn set_type(&mut self, limiter_type: RateLimiterType) -> Result<()> {
+        if let RateLimiterType::RollingWindow { window_seconds } = limiter_type {
+            require!(window_seconds > 0, OFTError::InvalidRateLimiter);
+        }
+        self.limiter_type = limiter_type;
+        self.set_capacity(self.capacity)
+    }
+
     pub fn set_rate(&mut self, refill_per_second: u64) -> Result<()> {
         self.refill(0)?;
         self.refill_per_second = refill_per_second;
@@ -32,34 +103,103 @@ impl RateLimiter {
     pub fn set_capacity(&mut self, capacity: u64) -> Result<()> {
         self.capacity = capacity;
         self.tokens = capacity;
+        self.window_usage = [0; RATE_LIMIT_WINDOW_SLOTS];
         self.last_refill_time = Clock::get()?.unix_timestamp.try_into().unwrap();
         Ok(())
     }
 
     pub fn refill(&mut self, extra_tokens: u64) -> Result<()> {
-        let mut new_tokens = extra_tokens;
         let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
-        if current_time > self.last_refill_time {
-            let time_elapsed_in_seconds = current_time - self.last_refill_time;
-            new_tokens = new_tokens
-                .saturating_add(time_elapsed_in_seconds.saturating_mul(self.refill_per_second));
-        }
-        self.tokens = std::cmp::min(self.capacity, self.tokens.saturating_add(new_tokens));
+        self.refill_at(extra_tokens, current_time);
+        Ok(())
+    }
+
+    pub fn try_consume(&mut self, amount: u64) -> Result<()> {
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        self.try_consume_at(amount, current_time)
+    }
 
+    /// The amount that can be consumed at current_time, without modifying the rate limiter.
+    pub fn available_at(&self, current_time: u64) -> u64 {
+        let mut limiter = self.clone();
+        limiter.refill_at(0, current_time);
+        limiter.available()
+    }
+
+    pub fn refill_at(&mut self, extra_tokens: u64, current_time: u64) {
+        match self.limiter_type {
+            RateLimiterType::TokenBucket => {
+                let mut new_tokens = extra_tokens;
+                if current_time > self.last_refill_time {
+                    let time_elapsed_in_seconds = current_time - self.last_refill_time;
+                    new_tokens = new_tokens.saturating_add(
+                        time_elapsed_in_seconds.saturating_mul(self.refill_per_second),
+                    );
+                }
+                self.tokens = std::cmp::min(self.capacity, self.tokens.saturating_add(new_tokens));
+            },
+            RateLimiterType::RollingWindow { window_seconds } => {
+                let slot_seconds = Self::window_slot_seconds(window_seconds);
+                let last_slot = self.last_refill_time / slot_seconds;
+                let current_slot = current_time / slot_seconds;
+                if current_slot > last_slot {
+                    // clear the slots that have left the window since the last update
+                    let expired =
+                        std::cmp::min(current_slot - last_slot, RATE_LIMIT_WINDOW_SLOTS as u64);
+                    for slot in (current_slot + 1 - expired)..=current_slot {
+                        self.window_usage[(slot % RATE_LIMIT_WINDOW_SLOTS as u64) as usize] = 0;
+                    }
+                }
+                // extra tokens give back the most recent usage first
+                let mut extra_tokens = extra_tokens;
+                for i in 0..RATE_LIMIT_WINDOW_SLOTS as u64 {
+                    if extra_tokens == 0 || i > current_slot {
+                        break;
+                    }
+                    let index = ((current_slot - i) % RATE_LIMIT_WINDOW_SLOTS as u64) as usize;
+                    let given_back = std::cmp::min(extra_tokens, self.window_usage[index]);
+                    self.window_usage[index] -= given_back;
+                    extra_tokens -= given_back;
+                }
+            },
+        }
         self.last_refill_time = current_time;
+    }
+
+    pub fn try_consume_at(&mut self, amount: u64, current_time: u64) -> Result<()> {
+        self.refill_at(0, current_time);
+        require!(amount <= self.available(), OFTError::RateLimitExceeded);
+        match self.limiter_type {
+            RateLimiterType::TokenBucket => {
+                self.tokens -= amount;
+            },
+            RateLimiterType::RollingWindow { window_seconds } => {
+                let current_slot = current_time / Self::window_slot_seconds(window_seconds);
+                self.window_usage[(current_slot % RATE_LIMIT_WINDOW_SLOTS as u64) as usize] +=
+                    amount;
+            },
+        }
         Ok(())
     }
 
-    pub fn try_consume(&mut self, amount: u64) -> Result<()> {
-        self.refill(0)?;
-        match self.tokens.checked_sub(amount) {
-            Some(new_tokens) => {
-                self.tokens = new_tokens;
-                Ok(())
+    fn available(&self) -> u64 {
+        match self.limiter_type {
+            RateLimiterType::TokenBucket => self.tokens,
+            RateLimiterType::RollingWindow { .. } => {
+                let used = self
+                    .window_usage
+                    .iter()
+                    .fold(0u64, |used, amount| used.saturating_add(*amount));
+                self.capacity.saturating_sub(used)
             },
-            None => Err(error!(OFTError::RateLimitExceeded)),
         }
     }
+
+    fn window_slot_seconds(window_seconds: u64) -> u64 {
+        // the current slot is partial, so the other slots must cover the whole window
+        let slots = RATE_LIMIT_WINDOW_SLOTS as u64 - 1;
+        std::cmp::max(1, window_seconds.div_ceil(slots))
+    }
 }
 
 #[derive(Clone, Default, AnchorSerialize, AnchorDeserialize, InitSpace)]
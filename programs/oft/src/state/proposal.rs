@@ -0,0 +1,97 @@
+use crate::*;
+
+pub const MAX_APPROVERS: usize = 10;
+
+/// Proposal queues an admin action on the oft_store. Once the timelock has elapsed and, if the
+/// oft_store has an approval threshold, enough approvers have approved it, anyone can execute it
+/// by passing it to the matching admin instruction, which closes it and refunds the rent to the
+/// proposer.
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub oft_store: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub eta: u64, // unix timestamp after which the action can be executed
+    #[max_len(MAX_APPROVERS)]
+    pub approvals: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub enum AdminAction {
+    SetOFTConfig(SetOFTConfigParams),
+    SetPeerConfig(SetPeerConfigParams),
+    WithdrawFee { token_dest: Pubkey, params: WithdrawFeeParams },
+    SweepSurplus { token_dest: Pubkey },
+    WithdrawLamportFee { receiver: Pubkey, params: WithdrawLamportFeeParams },
+    ReleaseQuarantine { token_dest: Pubkey, params: ReleaseQuarantineParams },
+}
+
+impl AdminAction {
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            AdminAction::SetOFTConfig(params) => params.required_role(),
+            AdminAction::SetPeerConfig(params) => params.config.required_role(),
+            AdminAction::WithdrawFee { .. }
+            | AdminAction::SweepSurplus { .. }
+            | AdminAction::WithdrawLamportFee { .. } => Some(Role::Treasurer),
+            AdminAction::ReleaseQuarantine { .. } => None,
+        }
+    }
+
+    /// Only config changes are timelocked. Pausing stays immediate so that incidents can be
+    /// contained while the timelock is enabled.
+    pub fn is_timelocked(&self) -> bool {
+        !matches!(
+            self,
+            AdminAction::SetOFTConfig(SetOFTConfigParams::Paused(_))
+                | AdminAction::WithdrawFee { .. }
+                | AdminAction::SweepSurplus { .. }
+                | AdminAction::WithdrawLamportFee { .. }
+                | AdminAction::ReleaseQuarantine { .. }
+        )
+    }
+}
+
+impl Proposal {
+    pub fn is_ready(&self) -> Result<bool> {
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        Ok(current_time >= self.eta)
+    }
+
+    /// Approvals from keys that are no longer approvers do not count.
+    pub fn is_approved(&self, oft_store: &OFTStore) -> bool {
+        let approvals =
+            self.approvals.iter().filter(|approver| oft_store.approvers.contains(approver)).count();
+        approvals >= oft_store.approval_threshold as usize
+    }
+}
+
+/// Checks that the signer may apply the admin action, or that the action comes from a ready proposal.
+/// While the timelock is enabled, timelocked actions can only be applied through a proposal.
+pub fn authorize_admin_action(
+    oft_store: &Account<OFTStore>,
+    signer: Pubkey,
+    role_assignment: &Option<Account<RoleAssignment>>,
+    proposal: &Option<Account<Proposal>>,
+    action: &AdminAction,
+) -> Result<()> {
+    if let Some(proposal) = proposal {
+        require!(
+            proposal.oft_store == oft_store.key() && proposal.action == *action,
+            OFTError::InvalidProposal
+        );
+        require!(proposal.is_ready()?, OFTError::TimelockNotElapsed);
+        require!(proposal.is_approved(oft_store), OFTError::ProposalNotApproved);
+        emit!(ProposalExecuted { oft_store: oft_store.key(), id: proposal.id, executor: signer });
+        return Ok(());
+    }
+    require!(
+        is_admin_or_role_holder(oft_store, signer, role_assignment, action.required_role()),
+        OFTError::Unauthorized
+    );
+    require!(oft_store.timelock_delay == 0 || !action.is_timelocked(), OFTError::TimelockRequired);
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::*;
+
+/// QuarantineRecord holds the amount of an inbound message that lz_receive or
+/// recover_lz_receive quarantined because its recipient was blocked. The tokens are held in
+/// token_escrow until release_quarantine pays them out to the recipient, once it's unblocked.
+#[account]
+#[derive(InitSpace)]
+pub struct QuarantineRecord {
+    pub oft_store: Pubkey,
+    pub guid: [u8; 32],
+    pub src_eid: u32,
+    pub recipient: Pubkey,
+    pub amount_ld: u64,
+    pub created_at: u64,
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
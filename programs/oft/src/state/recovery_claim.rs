@@ -0,0 +1,30 @@
+use crate::*;
+
+pub const DEFAULT_RECOVERY_CLAIM_TIMEOUT: u64 = 30 * 24 * 3600;
+
+/// RecoveryClaim holds the amount of an inbound message that recover_lz_receive cleared
+/// instead of delivering it, or that lz_receive couldn't unwrap to the recipient. The tokens are
+/// held in token_escrow until the recipient claims them, or the admin does once
+/// recovery_claim_timeout has elapsed.
+#[account]
+#[derive(InitSpace)]
+pub struct RecoveryClaim {
+    pub oft_store: Pubkey,
+    pub guid: [u8; 32],
+    pub src_eid: u32,
+    pub recipient: Pubkey,
+    pub amount_ld: u64,
+    pub created_at: u64,
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl RecoveryClaim {
+    pub fn can_claim(&self, oft_store: &OFTStore, signer: Pubkey, now: u64) -> bool {
+        if signer == self.recipient {
+            return true;
+        }
+        signer == oft_store.admin
+            && now >= self.created_at.saturating_add(oft_store.recovery_claim_timeout)
+    }
+}
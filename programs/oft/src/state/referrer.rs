@@ -0,0 +1,12 @@
+use crate::*;
+
+/// Referrer accrues referral_fee_share_bps of the oft fee of sends that name its wallet as
+/// the referrer. The rewards are held in token_escrow until claim_referral_rewards.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub oft_store: Pubkey,
+    pub wallet: Pubkey,
+    pub accrued_ld: u64,
+    pub bump: u8,
+}
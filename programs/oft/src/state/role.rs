@@ -0,0 +1,46 @@
+use crate::*;
+
+/// RoleAssignment grants a single role on an oft_store to a single holder.
+/// The admin implicitly holds every role.
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAssignment {
+    pub oft_store: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub enum Role {
+    FeeManager,
+    PeerManager,
+    RateLimitManager,
+    Pauser,
+    Unpauser,
+    Treasurer,
+    Recoverer,
+}
+
+impl RoleAssignment {
+    pub fn grants(&self, oft_store: Pubkey, holder: Pubkey, role: Role) -> bool {
+        self.oft_store == oft_store && self.holder == holder && self.role == role
+    }
+}
+
+/// Returns true if the signer is the admin of the oft_store or holds the required role.
+/// A required_role of None means only the admin is allowed.
+pub fn is_admin_or_role_holder(
+    oft_store: &Account<OFTStore>,
+    signer: Pubkey,
+    role_assignment: &Option<Account<RoleAssignment>>,
+    required_role: Option<Role>,
+) -> bool {
+    if oft_store.admin == signer {
+        return true;
+    }
+    match (required_role, role_assignment) {
+        (Some(role), Some(assignment)) => assignment.grants(oft_store.key(), signer, role),
+        _ => false,
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+/// SenderRateLimiter tracks the outbound allowance of a single sender towards a single peer.
+/// Its limits always follow the peer's sender_rate_limit so that config changes apply to
+/// existing senders as well.
+#[account]
+#[derive(InitSpace)]
+pub struct SenderRateLimiter {
+    pub rate_limiter: RateLimiter,
+    pub bump: u8,
+}
+
+#[derive(Clone, Default, AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq, Eq)]
+pub struct SenderRateLimit {
+    pub capacity: u64,
+    pub refill_per_second: u64,
+}
+
+impl SenderRateLimiter {
+    pub fn try_consume(&mut self, limit: &SenderRateLimit, amount: u64) -> Result<()> {
+        if self.rate_limiter.last_refill_time == 0 {
+            // first send of this sender starts with a full bucket
+            self.rate_limiter.set_capacity(limit.capacity)?;
+        } else {
+            self.rate_limiter.refill(0)?;
+            self.rate_limiter.capacity = limit.capacity;
+            self.rate_limiter.tokens = std::cmp::min(self.rate_limiter.tokens, limit.capacity);
+        }
+        self.rate_limiter.refill_per_second = limit.refill_per_second;
+        self.rate_limiter.try_consume(amount)
+    }
+
+    /// The amount try_consume would accept at current_time, without modifying the rate limiter.
+    pub fn available_at(&self, limit: &SenderRateLimit, current_time: u64) -> u64 {
+        if self.rate_limiter.last_refill_time == 0 {
+            return limit.capacity;
+        }
+        let mut rate_limiter = self.rate_limiter.clone();
+        rate_limiter.refill_at(0, current_time);
+        std::cmp::min(rate_limiter.tokens, limit.capacity)
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod test_fee_schedule {
+    use oft::state::{FeeSchedule, FeeTier};
+
+    fn schedule(min_fee_ld: Option<u64>, max_fee_ld: Option<u64>) -> FeeSchedule {
+        FeeSchedule {
+            tiers: vec![
+                FeeTier { min_amount_ld: 0, fee_bps: 100 },
+                FeeTier { min_amount_ld: 10_000, fee_bps: 50 },
+                FeeTier { min_amount_ld: 1_000_000, fee_bps: 10 },
+            ],
+            min_fee_ld,
+            max_fee_ld,
+        }
+    }
+
+    #[test]
+    fn test_tiers() {
+        let fee_schedule = schedule(None, None);
+        assert!(fee_schedule.validate().is_ok());
+        assert_eq!(fee_schedule.calculate_fee(0), 0);
+        assert_eq!(fee_schedule.calculate_fee(9_999), 99);
+        assert_eq!(fee_schedule.calculate_fee(10_000), 50);
+        assert_eq!(fee_schedule.calculate_fee(2_000_000), 2_000);
+    }
+
+    #[test]
+    fn test_fee_bounds() {
+        let fee_schedule = schedule(Some(20), Some(1_000));
+        assert_eq!(fee_schedule.calculate_fee(100), 20);
+        assert_eq!(fee_schedule.calculate_fee(10), 10);
+        assert_eq!(fee_schedule.calculate_fee(100_000), 500);
+        assert_eq!(fee_schedule.calculate_fee(2_000_000), 1_000);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(FeeSchedule::default().validate().is_err());
+        assert!(schedule(Some(2), Some(1)).validate().is_err());
+        let mut fee_schedule = schedule(None, None);
+        fee_schedule.tiers.swap(1, 2);
+        assert!(fee_schedule.validate().is_err());
+        let mut fee_schedule = schedule(None, None);
+        fee_schedule.tiers[0].min_amount_ld = 1;
+        assert!(fee_schedule.validate().is_err());
+        let mut fee_schedule = schedule(None, None);
+        fee_schedule.tiers[0].fee_bps = 10_000;
+        assert!(fee_schedule.validate().is_err());
+    }
+}
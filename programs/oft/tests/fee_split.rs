@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod test_fee_split {
+    use anchor_lang::prelude::Pubkey;
+    use oft::state::{validate_fee_split, FeeRecipient};
+
+    fn recipient(fee_bps: u16) -> FeeRecipient {
+        FeeRecipient { token_account: Pubkey::new_unique(), fee_bps }
+    }
+
+    #[test]
+    fn test_validate_fee_split() {
+        assert!(validate_fee_split(&[]).is_ok());
+        assert!(validate_fee_split(&[recipient(10_000)]).is_ok());
+        assert!(validate_fee_split(&[recipient(7_000), recipient(3_000)]).is_ok());
+        // shares must add up to 100%
+        assert!(validate_fee_split(&[recipient(7_000), recipient(2_000)]).is_err());
+        assert!(validate_fee_split(&[recipient(10_000), recipient(0)]).is_err());
+        // duplicate token accounts
+        let duplicate = recipient(5_000);
+        assert!(validate_fee_split(&[duplicate.clone(), duplicate]).is_err());
+    }
+
+    #[test]
+    fn test_share_of() {
+        let recipients = [recipient(5_000), recipient(3_333), recipient(1_667)];
+        let shares: Vec<u64> = recipients.iter().map(|r| r.share_of(1_000)).collect();
+        assert_eq!(shares, vec![500, 333, 166]);
+        // the rounding remainder stays in escrow
+        assert!(shares.iter().sum::<u64>() <= 1_000);
+    }
+}
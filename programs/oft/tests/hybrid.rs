@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod test_hybrid {
+    use oft::state::{OFTStore, OFTType};
+
+    #[test]
+    fn test_lock_up_to_cap() {
+        let mut oft_store =
+            OFTStore { oft_type: OFTType::Hybrid, lock_cap_ld: 1_000, ..Default::default() };
+        assert_eq!(oft_store.lock_up_to_cap(600), 600);
+        assert_eq!(oft_store.tvl_ld, 600);
+        // the part above the cap is burned
+        assert_eq!(oft_store.lock_up_to_cap(600), 400);
+        assert_eq!(oft_store.tvl_ld, 1_000);
+        assert_eq!(oft_store.lock_up_to_cap(100), 0);
+        assert_eq!(oft_store.tvl_ld, 1_000);
+        // lowering the cap below the tvl stops locking without unlocking
+        oft_store.lock_cap_ld = 500;
+        assert_eq!(oft_store.lock_up_to_cap(100), 0);
+        assert_eq!(oft_store.tvl_ld, 1_000);
+    }
+
+    #[test]
+    fn test_unlock_up_to_tvl() {
+        let mut oft_store = OFTStore {
+            oft_type: OFTType::Hybrid,
+            lock_cap_ld: 1_000,
+            tvl_ld: 700,
+            ..Default::default()
+        };
+        assert_eq!(oft_store.unlock_up_to_tvl(500), 500);
+        assert_eq!(oft_store.tvl_ld, 200);
+        // the part above the tvl is minted
+        assert_eq!(oft_store.unlock_up_to_tvl(500), 200);
+        assert_eq!(oft_store.tvl_ld, 0);
+        assert_eq!(oft_store.unlock_up_to_tvl(500), 0);
+    }
+}
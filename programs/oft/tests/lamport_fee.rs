@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod test_lamport_fee {
+    use oft::state::LamportFeeConfig;
+
+    #[test]
+    fn test_calculate_fee() {
+        let flat = LamportFeeConfig { flat_lamports: 5_000, lamports_per_token: 0 };
+        assert_eq!(flat.calculate_fee(1_000_000, 6), 5_000);
+
+        // 0.01 SOL per token, a fee of 2.5 tokens with 6 decimals
+        let priced = LamportFeeConfig { flat_lamports: 5_000, lamports_per_token: 10_000_000 };
+        assert_eq!(priced.calculate_fee(2_500_000, 6), 25_005_000);
+        assert_eq!(priced.calculate_fee(0, 6), 5_000);
+
+        let saturated = LamportFeeConfig { flat_lamports: 1, lamports_per_token: u64::MAX };
+        assert_eq!(saturated.calculate_fee(u64::MAX, 0), u64::MAX);
+    }
+}
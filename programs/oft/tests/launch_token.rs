@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test_launch_token {
+    use anchor_lang::{
+        prelude::*,
+        solana_program::{
+            entrypoint::ProgramResult,
+            instruction::Instruction,
+            program_error::ProgramError,
+            program_stubs::{set_syscall_stubs, SyscallStubs},
+        },
+    };
+    use anchor_spl::token_2022::spl_token_2022;
+    use oft::instructions::initialize_metadata;
+    use spl_token_metadata_interface::state::TokenMetadata;
+    use std::sync::{Arc, Mutex};
+
+    // Fails like the runtime when an account of the instruction isn't passed to the CPI
+    struct CpiStubs {
+        invoked: Arc<Mutex<Vec<Instruction>>>,
+    }
+
+    impl SyscallStubs for CpiStubs {
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            for meta in &instruction.accounts {
+                if !account_infos.iter().any(|account_info| account_info.key == &meta.pubkey) {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                }
+            }
+            self.invoked.lock().unwrap().push(instruction.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_initialize_metadata() {
+        let invoked = Arc::new(Mutex::new(vec![]));
+        set_syscall_stubs(Box::new(CpiStubs { invoked: invoked.clone() }));
+
+        let token_program = spl_token_2022::ID;
+        let (mint_key, admin_key, oft_store_key) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut mint_lamports, mut admin_lamports, mut oft_store_lamports) = (0, 0, 0);
+        let (mut mint_data, mut admin_data, mut oft_store_data) = (vec![], vec![], vec![]);
+        let token_mint = AccountInfo::new(
+            &mint_key,
+            false,
+            true,
+            &mut mint_lamports,
+            &mut mint_data,
+            &token_program,
+            false,
+            0,
+        );
+        let admin = AccountInfo::new(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &token_program,
+            false,
+            0,
+        );
+        let oft_store = AccountInfo::new(
+            &oft_store_key,
+            false,
+            true,
+            &mut oft_store_lamports,
+            &mut oft_store_data,
+            &oft::ID,
+            false,
+            0,
+        );
+        let metadata = TokenMetadata {
+            mint: mint_key,
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            ..Default::default()
+        };
+
+        initialize_metadata(&token_program, &token_mint, &admin, &oft_store, metadata, &[b"seed"])
+            .unwrap();
+        let invoked = invoked.lock().unwrap();
+        assert_eq!(invoked.len(), 1);
+        assert_eq!(invoked[0].program_id, token_program);
+        // metadata, update authority, mint, mint authority
+        let accounts: Vec<Pubkey> = invoked[0].accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(accounts, vec![mint_key, admin_key, mint_key, oft_store_key]);
+        assert!(invoked[0].accounts[3].is_signer);
+    }
+}
@@ -10,12 +10,12 @@ mod test_msg_codec {
         let amount_sd: u64 = 123456789;
         let sender: Pubkey = Pubkey::new_unique();
         let compose_msg: Option<Vec<u8>> = Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
-        let encoded = msg_codec::encode(send_to, amount_sd, sender, &compose_msg);
+        let encoded = msg_codec::encode(send_to, amount_sd, sender, &compose_msg, None);
         assert_eq!(encoded.len(), 72 + compose_msg.clone().unwrap().len());
         assert_eq!(msg_codec::send_to(&encoded), send_to);
         assert_eq!(msg_codec::amount_sd(&encoded), amount_sd);
         assert_eq!(
-            msg_codec::compose_msg(&encoded),
+            msg_codec::compose_msg(&encoded, false),
             Some([sender.to_bytes().as_ref(), compose_msg.unwrap().as_slice()].concat())
         );
     }
@@ -26,11 +26,35 @@ mod test_msg_codec {
         let amount_sd: u64 = 123456789;
         let sender: Pubkey = Pubkey::new_unique();
         let compose_msg: Option<Vec<u8>> = None;
-        let encoded = msg_codec::encode(send_to, amount_sd, sender, &compose_msg);
+        let encoded = msg_codec::encode(send_to, amount_sd, sender, &compose_msg, None);
         assert_eq!(encoded.len(), 40);
         assert_eq!(msg_codec::send_to(&encoded), send_to);
         assert_eq!(msg_codec::amount_sd(&encoded), amount_sd);
-        assert_eq!(msg_codec::compose_msg(&encoded), None);
+        assert_eq!(msg_codec::compose_msg(&encoded, true), None);
+        assert_eq!(msg_codec::to_token_account(&encoded, true), None);
+        // an empty compose msg is encoded without one
+        let encoded = msg_codec::encode(send_to, amount_sd, sender, &Some(vec![]), None);
+        assert_eq!(encoded.len(), 40);
+    }
+
+    #[test]
+    fn test_msg_codec_to_token_account() {
+        let send_to: [u8; 32] = [1; 32];
+        let amount_sd: u64 = 123456789;
+        let sender: Pubkey = Pubkey::new_unique();
+        let token_account: [u8; 32] = [2; 32];
+        let encoded = msg_codec::encode(send_to, amount_sd, sender, &None, Some(token_account));
+        assert_eq!(encoded.len(), 72);
+        assert_eq!(msg_codec::send_to(&encoded), send_to);
+        assert_eq!(msg_codec::amount_sd(&encoded), amount_sd);
+        assert_eq!(msg_codec::compose_msg(&encoded, true), None);
+        assert_eq!(msg_codec::to_token_account(&encoded, true), Some(token_account));
+        // the shortest composed message isn't taken for one
+        let composed = msg_codec::encode(send_to, amount_sd, sender, &Some(vec![1]), None);
+        assert_eq!(msg_codec::to_token_account(&composed, true), None);
+        // from other peers, a message of the same length is composed
+        assert_eq!(msg_codec::to_token_account(&encoded, false), None);
+        assert_eq!(msg_codec::compose_msg(&encoded, false), Some(token_account.to_vec()));
     }
 
     #[test]
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod test_proposal {
+    use anchor_lang::{
+        prelude::*,
+        solana_program::program_stubs::{set_syscall_stubs, SyscallStubs},
+    };
+    use oft::{
+        errors::OFTError,
+        instructions::SetOFTConfigParams,
+        state::{authorize_admin_action, AdminAction, OFTStore, Proposal, Role, RoleAssignment},
+    };
+
+    const NOW: i64 = 1_000_000;
+
+    struct ClockStubs;
+
+    impl SyscallStubs for ClockStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Clock) = Clock { unix_timestamp: NOW, ..Clock::default() } }
+            0
+        }
+    }
+
+    fn account<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+        key: Pubkey,
+        value: &T,
+    ) -> Account<'static, T> {
+        let mut data = vec![];
+        value.try_serialize(&mut data).unwrap();
+        let account_info = Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            false,
+            Box::leak(Box::new(1)),
+            Box::leak(data.into_boxed_slice()),
+            &oft::ID,
+            false,
+            0,
+        )));
+        Account::try_from(account_info).unwrap()
+    }
+
+    fn proposal(
+        oft_store: Pubkey,
+        action: AdminAction,
+        eta: u64,
+        approvals: Vec<Pubkey>,
+    ) -> Proposal {
+        Proposal {
+            oft_store,
+            id: 0,
+            proposer: Pubkey::new_unique(),
+            action,
+            eta,
+            approvals,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_authorize_without_proposal() {
+        set_syscall_stubs(Box::new(ClockStubs));
+        let (oft_store_key, admin, pauser) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let pause = AdminAction::SetOFTConfig(SetOFTConfigParams::Paused(true));
+        let set_fee = AdminAction::SetOFTConfig(SetOFTConfigParams::DefaultFee(10));
+        let role_assignment = Some(account(
+            Pubkey::new_unique(),
+            &RoleAssignment {
+                oft_store: oft_store_key,
+                holder: pauser,
+                role: Role::Pauser,
+                bump: 0,
+            },
+        ));
+
+        let oft_store = account(oft_store_key, &OFTStore { admin, ..Default::default() });
+        assert!(authorize_admin_action(&oft_store, admin, &None, &None, &set_fee).is_ok());
+        assert!(authorize_admin_action(&oft_store, pauser, &role_assignment, &None, &pause).is_ok());
+        assert_eq!(
+            authorize_admin_action(&oft_store, pauser, &role_assignment, &None, &set_fee)
+                .unwrap_err(),
+            OFTError::Unauthorized.into()
+        );
+
+        // while the timelock is enabled, config changes require a proposal but pausing doesn't
+        let oft_store =
+            account(oft_store_key, &OFTStore { admin, timelock_delay: 3600, ..Default::default() });
+        assert_eq!(
+            authorize_admin_action(&oft_store, admin, &None, &None, &set_fee).unwrap_err(),
+            OFTError::TimelockRequired.into()
+        );
+        assert!(authorize_admin_action(&oft_store, pauser, &role_assignment, &None, &pause).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_with_proposal() {
+        set_syscall_stubs(Box::new(ClockStubs));
+        let (oft_store_key, admin, executor) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let set_fee = AdminAction::SetOFTConfig(SetOFTConfigParams::DefaultFee(10));
+        let oft_store =
+            account(oft_store_key, &OFTStore { admin, timelock_delay: 3600, ..Default::default() });
+
+        // anyone can execute a ready proposal
+        let ready = Some(account(
+            Pubkey::new_unique(),
+            &proposal(oft_store_key, set_fee.clone(), NOW as u64, vec![]),
+        ));
+        assert!(authorize_admin_action(&oft_store, executor, &None, &ready, &set_fee).is_ok());
+        // for the proposed action of the oft_store only
+        let other_action = AdminAction::SetOFTConfig(SetOFTConfigParams::DefaultFee(20));
+        assert_eq!(
+            authorize_admin_action(&oft_store, executor, &None, &ready, &other_action).unwrap_err(),
+            OFTError::InvalidProposal.into()
+        );
+        let other_oft_store = Some(account(
+            Pubkey::new_unique(),
+            &proposal(Pubkey::new_unique(), set_fee.clone(), NOW as u64, vec![]),
+        ));
+        assert_eq!(
+            authorize_admin_action(&oft_store, executor, &None, &other_oft_store, &set_fee)
+                .unwrap_err(),
+            OFTError::InvalidProposal.into()
+        );
+        // once the timelock has elapsed
+        let pending = Some(account(
+            Pubkey::new_unique(),
+            &proposal(oft_store_key, set_fee.clone(), NOW as u64 + 1, vec![]),
+        ));
+        assert_eq!(
+            authorize_admin_action(&oft_store, admin, &None, &pending, &set_fee).unwrap_err(),
+            OFTError::TimelockNotElapsed.into()
+        );
+    }
+
+    #[test]
+    fn test_authorize_with_approvals() {
+        set_syscall_stubs(Box::new(ClockStubs));
+        let (oft_store_key, admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let approvers = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let set_fee = AdminAction::SetOFTConfig(SetOFTConfigParams::DefaultFee(10));
+        let oft_store = account(
+            oft_store_key,
+            &OFTStore {
+                admin,
+                approvers: approvers.clone(),
+                approval_threshold: 2,
+                ..Default::default()
+            },
+        );
+        let with_approvals = |approvals: Vec<Pubkey>| {
+            Some(account(
+                Pubkey::new_unique(),
+                &proposal(oft_store_key, set_fee.clone(), NOW as u64, approvals),
+            ))
+        };
+
+        let approved = with_approvals(vec![approvers[0], approvers[2]]);
+        assert!(authorize_admin_action(&oft_store, admin, &None, &approved, &set_fee).is_ok());
+        let not_approved = with_approvals(vec![approvers[1]]);
+        assert_eq!(
+            authorize_admin_action(&oft_store, admin, &None, &not_approved, &set_fee).unwrap_err(),
+            OFTError::ProposalNotApproved.into()
+        );
+        // approvals of removed approvers don't count
+        let stale = with_approvals(vec![approvers[1], Pubkey::new_unique()]);
+        assert_eq!(
+            authorize_admin_action(&oft_store, admin, &None, &stale, &set_fee).unwrap_err(),
+            OFTError::ProposalNotApproved.into()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod test_rate_limiter {
+    use oft::state::{
+        RateLimiter, RateLimiterType, SenderRateLimit, SenderRateLimiter, RATE_LIMIT_WINDOW_SLOTS,
+    };
+
+    fn token_bucket(capacity: u64, refill_per_second: u64, now: u64) -> RateLimiter {
+        RateLimiter {
+            capacity,
+            tokens: capacity,
+            refill_per_second,
+            last_refill_time: now,
+            limiter_type: RateLimiterType::TokenBucket,
+            window_usage: [0; RATE_LIMIT_WINDOW_SLOTS],
+        }
+    }
+
+    fn rolling_window(capacity: u64, window_seconds: u64, now: u64) -> RateLimiter {
+        RateLimiter {
+            limiter_type: RateLimiterType::RollingWindow { window_seconds },
+            ..token_bucket(capacity, 0, now)
+        }
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let mut limiter = token_bucket(1000, 10, 100);
+        limiter.try_consume_at(1000, 100).unwrap();
+        assert!(limiter.try_consume_at(1, 100).is_err());
+        assert_eq!(limiter.available_at(150), 500);
+        assert_eq!(limiter.available_at(1000), 1000);
+        limiter.refill_at(300, 110);
+        assert_eq!(limiter.tokens, 400);
+    }
+
+    #[test]
+    fn test_rolling_window() {
+        let window = 24 * 3600;
+        let mut limiter = rolling_window(1000, window, 0);
+        limiter.try_consume_at(600, 0).unwrap();
+        limiter.try_consume_at(400, window / 2).unwrap();
+        assert!(limiter.try_consume_at(1, window - 1).is_err());
+        assert_eq!(limiter.available_at(window - 1), 0);
+        // the first consumption leaves the window, the second one is still in it
+        assert_eq!(limiter.available_at(window * 11 / 10), 600);
+        assert_eq!(limiter.available_at(window * 2), 1000);
+    }
+
+    #[test]
+    fn test_rolling_window_never_undercounts() {
+        let window = 100;
+        let mut limiter = rolling_window(1000, window, 0);
+        limiter.try_consume_at(1000, 5).unwrap();
+        for now in 5..5 + window {
+            assert_eq!(limiter.available_at(now), 0);
+        }
+    }
+
+    #[test]
+    fn test_rolling_window_refill() {
+        let mut limiter = rolling_window(1000, 100, 0);
+        limiter.try_consume_at(700, 0).unwrap();
+        limiter.try_consume_at(300, 50).unwrap();
+        limiter.refill_at(500, 60);
+        assert_eq!(limiter.available_at(60), 500);
+        // the refill gave back the most recent usage first, the rest leaves with the first slot
+        assert_eq!(limiter.available_at(125), 1000);
+    }
+
+    #[test]
+    fn test_sender_rate_limiter_available() {
+        let limit = SenderRateLimit { capacity: 500, refill_per_second: 10 };
+        // a sender without sends yet starts with a full bucket
+        let new_sender = SenderRateLimiter { rate_limiter: RateLimiter::default(), bump: 0 };
+        assert_eq!(new_sender.available_at(&limit, 100), 500);
+
+        let mut rate_limiter = token_bucket(1000, 10, 100);
+        rate_limiter.try_consume_at(1000, 100).unwrap();
+        let sender = SenderRateLimiter { rate_limiter, bump: 0 };
+        assert_eq!(sender.available_at(&limit, 120), 200);
+        // capped by the current limit of the peer
+        assert_eq!(sender.available_at(&limit, 200), 500);
+    }
+}
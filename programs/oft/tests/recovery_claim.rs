@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod test_recovery_claim {
+    use anchor_lang::prelude::Pubkey;
+    use oft::state::{OFTStore, RecoveryClaim};
+
+    #[test]
+    fn test_can_claim() {
+        let (admin, recipient) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let oft_store = OFTStore { admin, recovery_claim_timeout: 100, ..Default::default() };
+        let recovery_claim = RecoveryClaim {
+            oft_store: Pubkey::new_unique(),
+            guid: [1; 32],
+            src_eid: 1,
+            recipient,
+            amount_ld: 1_000,
+            created_at: 1_000,
+            rent_payer: Pubkey::new_unique(),
+            bump: 0,
+        };
+        // the recipient can claim at any time
+        assert!(recovery_claim.can_claim(&oft_store, recipient, 1_000));
+        // the admin once the timeout has elapsed
+        assert!(!recovery_claim.can_claim(&oft_store, admin, 1_099));
+        assert!(recovery_claim.can_claim(&oft_store, admin, 1_100));
+        // anyone else never
+        assert!(!recovery_claim.can_claim(&oft_store, Pubkey::new_unique(), u64::MAX));
+    }
+}
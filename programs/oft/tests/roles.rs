@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test_roles {
+    use anchor_lang::prelude::*;
+    use oft::state::{is_admin_or_role_holder, OFTStore, Role, RoleAssignment};
+
+    fn account<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+        key: Pubkey,
+        value: &T,
+    ) -> Account<'static, T> {
+        let mut data = vec![];
+        value.try_serialize(&mut data).unwrap();
+        let account_info = Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            false,
+            Box::leak(Box::new(1)),
+            Box::leak(data.into_boxed_slice()),
+            &oft::ID,
+            false,
+            0,
+        )));
+        Account::try_from(account_info).unwrap()
+    }
+
+    #[test]
+    fn test_is_admin_or_role_holder() {
+        let (oft_store_key, admin, holder) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let oft_store = account(oft_store_key, &OFTStore { admin, ..Default::default() });
+        let role_assignment = Some(account(
+            Pubkey::new_unique(),
+            &RoleAssignment { oft_store: oft_store_key, holder, role: Role::Pauser, bump: 0 },
+        ));
+
+        // the admin holds every role, with or without a role assignment
+        assert!(is_admin_or_role_holder(&oft_store, admin, &None, Some(Role::Pauser)));
+        assert!(is_admin_or_role_holder(&oft_store, admin, &role_assignment, None));
+        // the holder only holds the assigned role
+        assert!(is_admin_or_role_holder(&oft_store, holder, &role_assignment, Some(Role::Pauser)));
+        assert!(!is_admin_or_role_holder(
+            &oft_store,
+            holder,
+            &role_assignment,
+            Some(Role::Unpauser)
+        ));
+        assert!(!is_admin_or_role_holder(&oft_store, holder, &None, Some(Role::Pauser)));
+        // admin only actions
+        assert!(!is_admin_or_role_holder(&oft_store, holder, &role_assignment, None));
+        // the assignment is for the holder only
+        let other = Pubkey::new_unique();
+        assert!(!is_admin_or_role_holder(&oft_store, other, &role_assignment, Some(Role::Pauser)));
+    }
+
+    #[test]
+    fn test_role_assignment_of_other_oft_store() {
+        let (admin, holder) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let oft_store = account(Pubkey::new_unique(), &OFTStore { admin, ..Default::default() });
+        let role_assignment = Some(account(
+            Pubkey::new_unique(),
+            &RoleAssignment {
+                oft_store: Pubkey::new_unique(),
+                holder,
+                role: Role::Treasurer,
+                bump: 0,
+            },
+        ));
+        assert!(!is_admin_or_role_holder(
+            &oft_store,
+            holder,
+            &role_assignment,
+            Some(Role::Treasurer)
+        ));
+    }
+}
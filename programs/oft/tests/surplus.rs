@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod test_surplus {
+    use oft::{errors::OFTError, state::OFTStore};
+
+    #[test]
+    fn test_surplus_ld() {
+        let oft_store = OFTStore { tvl_ld: 1_000, accrued_fee_ld: 100, ..Default::default() };
+        assert_eq!(oft_store.surplus_ld(1_150).unwrap(), 50);
+        assert_eq!(oft_store.surplus_ld(1_100).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_surplus_ld_below_reserved() {
+        // sweep_surplus can't take the escrow below what is reserved
+        let oft_store = OFTStore { tvl_ld: 1_000, accrued_fee_ld: 100, ..Default::default() };
+        assert_eq!(oft_store.surplus_ld(1_099).unwrap_err(), OFTError::EscrowBelowReserved.into());
+        assert_eq!(oft_store.surplus_ld(0).unwrap_err(), OFTError::EscrowBelowReserved.into());
+    }
+
+    #[test]
+    fn test_reserved_ld() {
+        // quarantined and claimable tokens are held in escrow for their recipients
+        let oft_store = OFTStore {
+            tvl_ld: 1_000,
+            accrued_fee_ld: 100,
+            accrued_referral_ld: 10,
+            quarantined_ld: 200,
+            claimable_ld: 300,
+            ..Default::default()
+        };
+        assert_eq!(oft_store.reserved_ld().unwrap(), 1_610);
+        assert_eq!(oft_store.surplus_ld(1_700).unwrap(), 90);
+        assert_eq!(oft_store.surplus_ld(1_609).unwrap_err(), OFTError::EscrowBelowReserved.into());
+
+        let oft_store = OFTStore { tvl_ld: u64::MAX, claimable_ld: 1, ..Default::default() };
+        assert_eq!(oft_store.reserved_ld().unwrap_err(), OFTError::MathOverflow.into());
+    }
+}